@@ -1,5 +1,703 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    rc::Rc,
+};
+
+use crate::{
+    directed_graph::DirectedGraph,
+    linear_algebra::{Field, Matrix},
+    topology::{ContinuousMap, FiniteTopologicalSpace, TopologicalSpace},
+};
+
+/// A reflexive and transitive relation, where `compare(a, b)` holds when
+/// `a ≤ b`.
 pub trait PreOrderedSet {
-    type Element;
+    type Element: Clone + Eq + Hash;
     fn compare(&self, element_a: Self::Element, element_b: Self::Element) -> bool;
+    /// The elements `b` with `element ≤ b`.
     fn upper_set(&self, element: Self::Element) -> Vec<Self::Element>;
 }
+
+/// A preorder on finitely many elements.
+///
+/// Elements with `a ≤ b` and `b ≤ a` are equivalent. The provided methods
+/// work with the strict order `a < b`, meaning `a ≤ b` but not `b ≤ a`, and
+/// so treat equivalent elements as incomparable.
+pub trait FinitePreOrderedSet: PreOrderedSet {
+    fn elements(&self) -> Vec<Self::Element>;
+
+    /// The elements `a` with `a ≤ element`, the principal ideal of `element`.
+    /// The upper set is the principal filter.
+    fn lower_set(&self, element: Self::Element) -> Vec<Self::Element> {
+        self.elements()
+            .into_iter()
+            .filter(|a| self.compare(a.clone(), element.clone()))
+            .collect()
+    }
+
+    /// The smallest down-set containing `set`.
+    fn down_closure(&self, set: &HashSet<Self::Element>) -> HashSet<Self::Element> {
+        self.elements()
+            .into_iter()
+            .filter(|a| set.iter().any(|b| self.compare(a.clone(), b.clone())))
+            .collect()
+    }
+
+    /// The smallest up-set containing `set`.
+    fn up_closure(&self, set: &HashSet<Self::Element>) -> HashSet<Self::Element> {
+        set.iter().flat_map(|a| self.upper_set(a.clone())).collect()
+    }
+
+    /// Whether everything below an element of `set` lies in `set`.
+    fn is_down_set(&self, set: &HashSet<Self::Element>) -> bool {
+        self.down_closure(set) == *set
+    }
+
+    /// Whether everything above an element of `set` lies in `set`. These are
+    /// the open sets of the Alexandrov topology.
+    fn is_up_set(&self, set: &HashSet<Self::Element>) -> bool {
+        self.up_closure(set) == *set
+    }
+
+    /// Whether `set` is a nonempty down-set in which every two elements have
+    /// an upper bound. In a finite order these are the principal ideals up to
+    /// equivalent generators.
+    fn is_ideal(&self, set: &HashSet<Self::Element>) -> bool {
+        !set.is_empty()
+            && self.is_down_set(set)
+            && set.iter().all(|a| {
+                set.iter().all(|b| {
+                    set.iter().any(|c| {
+                        self.compare(a.clone(), c.clone()) && self.compare(b.clone(), c.clone())
+                    })
+                })
+            })
+    }
+
+    /// Whether `set` is a nonempty up-set in which every two elements have a
+    /// lower bound.
+    fn is_filter(&self, set: &HashSet<Self::Element>) -> bool {
+        !set.is_empty()
+            && self.is_up_set(set)
+            && set.iter().all(|a| {
+                set.iter().all(|b| {
+                    set.iter().any(|c| {
+                        self.compare(c.clone(), a.clone()) && self.compare(c.clone(), b.clone())
+                    })
+                })
+            })
+    }
+
+    /// Whether `set` contains everything between two of its elements, which
+    /// makes it the intersection of its up and down closures.
+    fn is_convex(&self, set: &HashSet<Self::Element>) -> bool {
+        let below = self.down_closure(set);
+        self.up_closure(set).intersection(&below).count() == set.len()
+    }
+
+    /// The covering relation, with an edge from `a` to `b` when `a < b` and
+    /// nothing lies strictly between them. Its transitive closure is the
+    /// strict order.
+    fn hasse_diagram(&self) -> DirectedGraph<Self::Element> {
+        strict_order(self).transitive_reduction()
+    }
+
+    /// The elements listed so that smaller elements come first.
+    fn linear_extension(&self) -> Vec<Self::Element> {
+        strict_order(self)
+            .topological_sort()
+            .expect("strict orders are acyclic")
+    }
+
+    /// The number of elements in a longest chain `a_1 < a_2 < ... < a_k`.
+    fn height(&self) -> usize {
+        let order = strict_order(self);
+        let mut longest: HashMap<Self::Element, usize> = HashMap::new();
+        for element in order.topological_sort().expect("strict orders are acyclic") {
+            let below = order
+                .edges
+                .iter()
+                .filter(|(_, b)| *b == element)
+                .map(|(a, _)| longest[a])
+                .max()
+                .unwrap_or(0);
+            longest.insert(element, below + 1);
+        }
+        longest.into_values().max().unwrap_or(0)
+    }
+
+    /// The size of a largest antichain, counting equivalent elements once.
+    ///
+    /// By Dilworth's theorem this is the least number of chains covering the
+    /// order, which is the number of elements minus a maximum matching
+    /// between the lower and upper ends of the strict relations.
+    fn width(&self) -> usize {
+        let mut classes: Vec<Self::Element> = Vec::new();
+        for element in self.elements() {
+            if !classes.iter().any(|c| {
+                self.compare(c.clone(), element.clone()) && self.compare(element.clone(), c.clone())
+            }) {
+                classes.push(element);
+            }
+        }
+        let n = classes.len();
+        let above: Vec<Vec<usize>> = (0..n)
+            .map(|i| {
+                (0..n)
+                    .filter(|&j| {
+                        i != j
+                            && self.compare(classes[i].clone(), classes[j].clone())
+                            && !self.compare(classes[j].clone(), classes[i].clone())
+                    })
+                    .collect()
+            })
+            .collect();
+        let mut matched: Vec<Option<usize>> = vec![None; n];
+        let matching = (0..n)
+            .filter(|&i| augment(i, &above, &mut matched, &mut vec![false; n]))
+            .count();
+        n - matching
+    }
+}
+
+// The graph of the strict order, with vertices in the order of `elements`.
+fn strict_order<O: FinitePreOrderedSet + ?Sized>(order: &O) -> DirectedGraph<O::Element> {
+    let elements = order.elements();
+    let mut edges = HashSet::new();
+    for a in &elements {
+        for b in &elements {
+            if order.compare(a.clone(), b.clone()) && !order.compare(b.clone(), a.clone()) {
+                edges.insert((a.clone(), b.clone()));
+            }
+        }
+    }
+    DirectedGraph::new(elements, edges)
+}
+
+// Looks for an augmenting path from `i` in Kuhn's bipartite matching, where
+// `matched[j]` is the lower end matched to the upper end `j`.
+fn augment(
+    i: usize,
+    above: &[Vec<usize>],
+    matched: &mut [Option<usize>],
+    visited: &mut [bool],
+) -> bool {
+    for &j in &above[i] {
+        if visited[j] {
+            continue;
+        }
+        visited[j] = true;
+        if matched[j].is_none_or(|k| augment(k, above, matched, visited)) {
+            matched[j] = Some(i);
+            return true;
+        }
+    }
+    false
+}
+
+/// The Alexandrov topology of a finite preorder, whose open sets are the
+/// up-sets. The smallest neighborhood of an element is its upper set.
+pub struct AlexandrovSpace<O: FinitePreOrderedSet> {
+    pub order: O,
+}
+
+impl<O: FinitePreOrderedSet> AlexandrovSpace<O> {
+    pub fn new(order: O) -> Self {
+        Self { order }
+    }
+}
+
+impl<O: FinitePreOrderedSet> TopologicalSpace for AlexandrovSpace<O> {
+    type Point = O::Element;
+    type OpenSet = HashSet<O::Element>;
+
+    fn points(&self) -> HashSet<Self::Point> {
+        self.order.elements().into_iter().collect()
+    }
+
+    fn neighborhood(&self, point: Self::Point) -> Self::OpenSet {
+        self.order.upper_set(point).into_iter().collect()
+    }
+
+    fn is_open(&self, set: Self::OpenSet) -> bool {
+        let points = self.points();
+        set.iter().all(|p| {
+            points.contains(p)
+                && self
+                    .order
+                    .upper_set(p.clone())
+                    .iter()
+                    .all(|q| set.contains(q))
+        })
+    }
+}
+
+impl<O: FinitePreOrderedSet> FiniteTopologicalSpace for AlexandrovSpace<O> {
+    fn minimal_neighborhood(&self, point: Self::Point) -> HashSet<Self::Point> {
+        self.neighborhood(point)
+    }
+}
+
+/// A map between the elements of two preorders. Whether it preserves the
+/// order can be checked with [`MonotoneMap::is_monotone`].
+pub struct MonotoneMap<P: PreOrderedSet, Q: PreOrderedSet> {
+    map: Rc<dyn Fn(P::Element) -> Q::Element>,
+}
+
+impl<P: PreOrderedSet, Q: PreOrderedSet> Clone for MonotoneMap<P, Q> {
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<P: PreOrderedSet, Q: PreOrderedSet> MonotoneMap<P, Q> {
+    pub fn new(map: impl Fn(P::Element) -> Q::Element + 'static) -> Self {
+        Self { map: Rc::new(map) }
+    }
+
+    pub fn apply(&self, element: P::Element) -> Q::Element {
+        (self.map)(element)
+    }
+
+    /// Checks that `a ≤ b` implies `f(a) ≤ f(b)`. It is enough to check the
+    /// upper sets of the elements.
+    pub fn is_monotone(&self, domain: &P, codomain: &Q) -> bool
+    where
+        P: FinitePreOrderedSet,
+    {
+        domain.elements().into_iter().all(|a| {
+            let image = self.apply(a.clone());
+            domain
+                .upper_set(a)
+                .into_iter()
+                .all(|b| codomain.compare(image.clone(), self.apply(b)))
+        })
+    }
+
+    /// The same map between the Alexandrov spaces. It is continuous exactly
+    /// when the map is monotone.
+    pub fn alexandrov_map(&self) -> ContinuousMap<AlexandrovSpace<P>, AlexandrovSpace<Q>>
+    where
+        P: FinitePreOrderedSet,
+        Q: FinitePreOrderedSet,
+        P::Element: 'static,
+        Q::Element: 'static,
+    {
+        let map = self.map.clone();
+        ContinuousMap::new(move |p| map(p))
+    }
+
+    pub fn then<R: PreOrderedSet>(&self, other: &MonotoneMap<Q, R>) -> MonotoneMap<P, R>
+    where
+        P::Element: 'static,
+        Q::Element: 'static,
+        R::Element: 'static,
+    {
+        let (first, second) = (self.map.clone(), other.map.clone());
+        MonotoneMap::new(move |p| second(first(p)))
+    }
+}
+
+impl<'a, 'b, X: FiniteTopologicalSpace + 'static, Y: FiniteTopologicalSpace + 'static>
+    MonotoneMap<SpecializationOrder<'a, X>, SpecializationOrder<'b, Y>>
+{
+    /// A continuous map as a map between the specialization orders, which it
+    /// always preserves.
+    pub fn from_continuous(map: &ContinuousMap<X, Y>) -> Self {
+        let map = map.clone();
+        Self::new(move |p| map.apply(p))
+    }
+}
+
+/// The specialization preorder of a finite space, with `a ≤ b` when `b` lies
+/// in every open set containing `a`, or equivalently when `a` lies in the
+/// closure of `b`. Its Alexandrov topology is the topology of the space.
+pub struct SpecializationOrder<'a, X: FiniteTopologicalSpace> {
+    pub space: &'a X,
+    upper_sets: HashMap<X::Point, HashSet<X::Point>>,
+}
+
+impl<'a, X: FiniteTopologicalSpace> SpecializationOrder<'a, X> {
+    pub fn new(space: &'a X) -> Self {
+        let upper_sets = space
+            .points()
+            .into_iter()
+            .map(|p| (p.clone(), space.minimal_neighborhood(p)))
+            .collect();
+        Self { space, upper_sets }
+    }
+}
+
+impl<X: FiniteTopologicalSpace> Clone for SpecializationOrder<'_, X> {
+    fn clone(&self) -> Self {
+        Self {
+            space: self.space,
+            upper_sets: self.upper_sets.clone(),
+        }
+    }
+}
+
+impl<X: FiniteTopologicalSpace> PreOrderedSet for SpecializationOrder<'_, X> {
+    type Element = X::Point;
+
+    fn compare(&self, element_a: Self::Element, element_b: Self::Element) -> bool {
+        self.upper_sets
+            .get(&element_a)
+            .is_some_and(|u| u.contains(&element_b))
+    }
+
+    fn upper_set(&self, element: Self::Element) -> Vec<Self::Element> {
+        self.upper_sets
+            .get(&element)
+            .map(|u| u.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+impl<X: FiniteTopologicalSpace> FinitePreOrderedSet for SpecializationOrder<'_, X> {
+    fn elements(&self) -> Vec<Self::Element> {
+        self.upper_sets.keys().cloned().collect()
+    }
+}
+
+/// A sheaf of vector spaces on a finite preorder, given by a stalk at every
+/// element and a restriction map for every relation `x ≤ y`.
+///
+/// In the Alexandrov topology the smallest neighborhood of `y` lies inside the
+/// one of `x` whenever `x ≤ y`, so restrictions go from the stalk at `x` to the
+/// stalk at `y`. The sections over an up-set are the families of stalk values
+/// that agree along every restriction, which is the limit of the diagram.
+pub struct AlexandrovSheaf<O: FinitePreOrderedSet, F: Field> {
+    pub order: O,
+    stalks: HashMap<O::Element, usize>,
+    restrictions: HashMap<(O::Element, O::Element), Matrix<F>>,
+}
+
+impl<O: FinitePreOrderedSet, F: Field> AlexandrovSheaf<O, F> {
+    /// A sheaf with the given stalk dimensions and no restrictions yet.
+    /// Elements without a stalk get the zero space.
+    pub fn new(order: O, stalks: HashMap<O::Element, usize>) -> Self {
+        Self {
+            order,
+            stalks,
+            restrictions: HashMap::new(),
+        }
+    }
+
+    pub fn stalk(&self, element: &O::Element) -> usize {
+        self.stalks.get(element).copied().unwrap_or(0)
+    }
+
+    /// Sets the restriction from the stalk at `from` to the stalk at `to`,
+    /// which must satisfy `from ≤ to`.
+    pub fn set_restriction(&mut self, from: O::Element, to: O::Element, map: Matrix<F>) {
+        assert!(
+            self.order.compare(from.clone(), to.clone()),
+            "Restrictions go from an element to one above it",
+        );
+        assert!(
+            map.rows() == self.stalk(&to) && map.cols() == self.stalk(&from),
+            "The restriction must map the stalk at `from` to the stalk at `to`",
+        );
+        self.restrictions.insert((from, to), map);
+    }
+
+    /// The restriction from `from` to `to`. A missing restriction from an
+    /// element to itself is the identity.
+    pub fn restriction(&self, from: &O::Element, to: &O::Element) -> Option<Matrix<F>> {
+        match self.restrictions.get(&(from.clone(), to.clone())) {
+            Some(map) => Some(map.clone()),
+            None if from == to => Some(Matrix::identity(self.stalk(from))),
+            None => None,
+        }
+    }
+
+    /// Checks that every relation has a restriction, that restrictions from
+    /// an element to itself are identities, and that restricting in two steps
+    /// agrees with restricting directly.
+    pub fn is_functorial(&self) -> bool {
+        let elements = self.order.elements();
+        for x in &elements {
+            if self.restriction(x, x) != Some(Matrix::identity(self.stalk(x))) {
+                return false;
+            }
+            for y in self.order.upper_set(x.clone()) {
+                let Some(xy) = self.restriction(x, &y) else {
+                    return false;
+                };
+                for z in self.order.upper_set(y.clone()) {
+                    match (self.restriction(&y, &z), self.restriction(x, &z)) {
+                        (Some(yz), Some(xz)) if &yz * &xy == xz => {}
+                        _ => return false,
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    /// A basis of the sections over `open`, which should be an up-set. Each
+    /// section lists the values at the elements of `open`, in the order of
+    /// [`FinitePreOrderedSet::elements`], one after another.
+    pub fn sections_over(&self, open: &HashSet<O::Element>) -> Vec<Vec<F>> {
+        let elements: Vec<O::Element> = self
+            .order
+            .elements()
+            .into_iter()
+            .filter(|e| open.contains(e))
+            .collect();
+        let mut offsets = HashMap::new();
+        let mut total = 0;
+        for e in &elements {
+            offsets.insert(e.clone(), total);
+            total += self.stalk(e);
+        }
+        let mut constraints = Vec::new();
+        for x in &elements {
+            for y in self.order.upper_set(x.clone()) {
+                if y == *x || !open.contains(&y) {
+                    continue;
+                }
+                let map = self
+                    .restriction(x, &y)
+                    .expect("every relation needs a restriction");
+                // The restriction of the value at `x` equals the value at `y`.
+                for row in 0..map.rows() {
+                    let mut constraint = vec![F::zero(); total];
+                    for col in 0..map.cols() {
+                        constraint[offsets[x] + col] = map.get(row, col);
+                    }
+                    constraint[offsets[&y] + row] = constraint[offsets[&y] + row] - F::one();
+                    constraints.push(constraint);
+                }
+            }
+        }
+        let mut system = Matrix::zeros(constraints.len(), total);
+        for (i, constraint) in constraints.iter().enumerate() {
+            for (j, value) in constraint.iter().enumerate() {
+                system.set(i, j, *value);
+            }
+        }
+        system.nullspace()
+    }
+
+    /// A basis of the global sections, the limit over the whole order.
+    pub fn global_sections(&self) -> Vec<Vec<F>> {
+        self.sections_over(&self.order.elements().into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        linear_algebra::{Rational, Z2},
+        posets::{Divisibility, TotalOrder},
+        spaces::finite_topology::FiniteTopology,
+        test_support::set,
+    };
+
+    // The chain 0 ≤ 1 ≤ 2.
+    fn chain() -> TotalOrder<u32> {
+        TotalOrder::new(0..3)
+    }
+
+    #[test]
+    fn up_sets_are_open() {
+        let space = AlexandrovSpace::new(Divisibility::up_to(6));
+        assert!(space.is_open(set(&[2, 4, 6])));
+        assert!(space.is_open(set(&[6])));
+        assert!(!space.is_open(set(&[3])));
+        assert!(!space.is_open(set(&[7])));
+        assert_eq!(space.minimal_neighborhood(3), set(&[3, 6]));
+        assert_eq!(space.closure(&set(&[6])), set(&[1, 2, 3, 6]));
+    }
+
+    #[test]
+    fn hasse_diagram_of_divisors() {
+        let order = Divisibility::up_to(12);
+        let hasse = order.hasse_diagram();
+        assert_eq!(hasse.successors(&1), vec![2, 3, 5, 7, 11]);
+        assert_eq!(hasse.successors(&2), vec![4, 6, 10]);
+        assert!(!hasse.edges.contains(&(2, 12)));
+        assert_eq!(
+            hasse.transitive_closure().edges.len(),
+            (1..=12)
+                .flat_map(|a| (1..=12).map(move |b| (a, b)))
+                .filter(|&(a, b)| a != b && b % a == 0)
+                .count()
+        );
+        let extension = order.linear_extension();
+        let position = |x: u64| extension.iter().position(|&e| e == x).unwrap();
+        assert!(position(3) < position(6) && position(6) < position(12));
+    }
+
+    #[test]
+    fn height_and_width() {
+        // 1 | 2 | 4 | 8 is a longest chain, and 7..=12 is a largest antichain.
+        let order = Divisibility::up_to(12);
+        assert_eq!(order.height(), 4);
+        assert_eq!(order.width(), 6);
+    }
+
+    #[test]
+    fn equivalent_elements_count_once() {
+        let space = FiniteTopology::from_subbasis(
+            set(&['a', 'b', 'c', 'd']),
+            vec![set(&['a']), set(&['a', 'b'])],
+        );
+        let order = SpecializationOrder::new(&space);
+        // 'c' and 'd' are equivalent and lie below 'b', which lies below 'a'.
+        assert_eq!(order.height(), 3);
+        assert_eq!(order.width(), 1);
+        assert_eq!(order.hasse_diagram().edges.len(), 3);
+    }
+
+    // The constant sheaf with stalk F^k, whose restrictions are identities.
+    fn constant_sheaf<O: FinitePreOrderedSet>(order: O, k: usize) -> AlexandrovSheaf<O, Z2> {
+        let stalks = order.elements().into_iter().map(|e| (e, k)).collect();
+        let mut sheaf = AlexandrovSheaf::new(order, stalks);
+        for x in sheaf.order.elements() {
+            for y in sheaf.order.upper_set(x.clone()) {
+                sheaf.set_restriction(x.clone(), y, Matrix::identity(k));
+            }
+        }
+        sheaf
+    }
+
+    #[test]
+    fn constant_sheaf_counts_components() {
+        let sheaf = constant_sheaf(Divisibility::up_to(6), 2);
+        assert!(sheaf.is_functorial());
+        assert_eq!(sheaf.global_sections().len(), 2);
+
+        // Two disjoint chains.
+        let space = FiniteTopology::from_subbasis(
+            set(&['a', 'b', 'c', 'd']),
+            vec![set(&['a']), set(&['a', 'b']), set(&['c']), set(&['c', 'd'])],
+        );
+        let sheaf = constant_sheaf(SpecializationOrder::new(&space), 1);
+        assert_eq!(sheaf.global_sections().len(), 2);
+        assert_eq!(sheaf.sections_over(&set(&['a', 'b'])).len(), 1);
+        assert_eq!(sheaf.sections_over(&HashSet::new()).len(), 0);
+    }
+
+    #[test]
+    fn sections_agree_along_restrictions() {
+        // A skyscraper at 2 with a nonzero map into the stalk at 4.
+        let order = Divisibility::up_to(4);
+        let stalks = HashMap::from([(2, 1), (4, 2)]);
+        let mut sheaf = AlexandrovSheaf::<_, Rational>::new(order, stalks);
+        sheaf.set_restriction(1, 1, Matrix::identity(0));
+        sheaf.set_restriction(1, 2, Matrix::zeros(1, 0));
+        sheaf.set_restriction(1, 3, Matrix::zeros(0, 0));
+        sheaf.set_restriction(1, 4, Matrix::zeros(2, 0));
+        let into_four = Matrix::from_rows(vec![vec![Rational::from(1)], vec![Rational::from(2)]]);
+        sheaf.set_restriction(2, 4, into_four);
+        assert!(sheaf.is_functorial());
+        // Everything restricts from the zero stalk at 1.
+        assert!(sheaf.global_sections().is_empty());
+        // Over the neighborhood of 2 a section is determined by its value at 2.
+        let sections = sheaf.sections_over(&set(&[2, 4]));
+        assert_eq!(sections.len(), 1);
+        let s = &sections[0];
+        assert_eq!(s.len(), 3);
+        assert_eq!(s[1], s[0] * Rational::from(1));
+        assert_eq!(s[2], s[0] * Rational::from(2));
+        // Over the up-set {4} every value is a section.
+        assert_eq!(sheaf.sections_over(&set(&[4])).len(), 2);
+    }
+
+    #[test]
+    fn composites_are_checked() {
+        let mut sheaf = constant_sheaf(chain(), 1);
+        assert!(sheaf.is_functorial());
+        sheaf.set_restriction(0, 2, Matrix::zeros(1, 1));
+        assert!(!sheaf.is_functorial());
+        assert_eq!(sheaf.global_sections().len(), 0);
+    }
+
+    #[test]
+    fn down_sets_ideals_and_filters() {
+        let order = Divisibility::up_to(12);
+        assert_eq!(order.lower_set(12), vec![1, 2, 3, 4, 6, 12]);
+        assert!(order.is_down_set(&set(&[1, 2, 4])));
+        assert!(!order.is_down_set(&set(&[2, 4])));
+        assert!(order.is_up_set(&set(&order.upper_set(5))));
+        assert_eq!(order.down_closure(&set(&[4, 9])), set(&[1, 2, 3, 4, 9]));
+        assert_eq!(order.up_closure(&set(&[5, 6])), set(&[5, 6, 10, 12]));
+
+        assert!(order.is_ideal(&set(&order.lower_set(6))));
+        // 4 and 3 have no upper bound among their divisors.
+        assert!(!order.is_ideal(&set(&[1, 2, 3, 4])));
+        assert!(order.is_filter(&set(&order.upper_set(3))));
+        // 5 and 7 have a lower bound in the order, but not in the set.
+        assert!(!order.is_filter(&set(&[5, 7, 10])));
+
+        assert!(order.is_convex(&set(&[2, 4, 6, 12])));
+        assert!(!order.is_convex(&set(&[2, 12])));
+        assert!(order.is_convex(&HashSet::new()));
+    }
+
+    #[test]
+    fn monotone_maps_are_continuous() {
+        let halve = MonotoneMap::<TotalOrder<u32>, TotalOrder<u32>>::new(|x| x / 2);
+        assert!(halve.is_monotone(&chain(), &chain()));
+        let flip = MonotoneMap::<TotalOrder<u32>, TotalOrder<u32>>::new(|x| 2 - x);
+        assert!(!flip.is_monotone(&chain(), &chain()));
+
+        let (domain, codomain) = (AlexandrovSpace::new(chain()), AlexandrovSpace::new(chain()));
+        assert!(halve.alexandrov_map().is_continuous(&domain, &codomain));
+        assert!(!flip.alexandrov_map().is_continuous(&domain, &codomain));
+        assert!(!halve.then(&flip).is_monotone(&chain(), &chain()));
+        assert!(flip.then(&flip).is_monotone(&chain(), &chain()));
+
+        // The number of prime factors does not decrease along divisibility.
+        let to_chain = MonotoneMap::<Divisibility, TotalOrder<u32>>::new(|n| match n {
+            1 => 0,
+            2 | 3 | 5 | 7 | 11 => 1,
+            _ => 2,
+        });
+        assert!(to_chain.is_monotone(&Divisibility::up_to(12), &chain()));
+    }
+
+    #[test]
+    fn order_round_trip() {
+        let space = AlexandrovSpace::new(Divisibility::up_to(12));
+        let order = SpecializationOrder::new(&space);
+        for a in 1..=12 {
+            for b in 1..=12 {
+                assert_eq!(order.compare(a, b), space.order.compare(a, b));
+            }
+        }
+        assert_eq!(set(&order.elements()), space.points());
+    }
+
+    #[test]
+    fn topology_round_trip() {
+        let space = FiniteTopology::from_subbasis(
+            set(&['a', 'b', 'c', 'd']),
+            vec![set(&['a']), set(&['a', 'b']), set(&['c', 'd'])],
+        );
+        let order = SpecializationOrder::new(&space);
+        assert!(order.compare('b', 'a'));
+        assert!(!order.compare('a', 'b'));
+        // 'c' and 'd' are not told apart, so they are below each other.
+        assert!(order.compare('c', 'd') && order.compare('d', 'c'));
+
+        let rebuilt = AlexandrovSpace::new(order);
+        let mut expected = space.open_sets();
+        let mut open_sets = rebuilt.open_sets();
+        let key = |s: &HashSet<char>| {
+            let mut points: Vec<char> = s.iter().cloned().collect();
+            points.sort();
+            points
+        };
+        expected.sort_by_key(key);
+        open_sets.sort_by_key(key);
+        assert_eq!(open_sets, expected);
+    }
+}
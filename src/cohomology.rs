@@ -0,0 +1,267 @@
+use std::hash::Hash;
+
+use crate::{
+    linear_algebra::{extend_basis, Field, Matrix},
+    spaces::simplicial_complex::SimplicialComplex,
+};
+
+/// A cochain assigns a coefficient to every simplex of one dimension. Values
+/// follow the order of [`SimplicialComplex::simplices_of_dim`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cochain<F: Field> {
+    pub degree: usize,
+    pub values: Vec<F>,
+}
+
+/// A cohomology class, given by its coordinates in the basis of
+/// [`CohomologyRing::basis`] for its degree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CohomologyClass<F: Field> {
+    pub degree: usize,
+    pub coordinates: Vec<F>,
+}
+
+impl<F: Field> CohomologyClass<F> {
+    pub fn is_zero(&self) -> bool {
+        self.coordinates.iter().all(|x| x.is_zero())
+    }
+}
+
+/// The cohomology of a simplicial complex with coefficients in `F`, together
+/// with the graded ring structure given by the cup product.
+pub struct CohomologyRing<V: Ord + Hash + Clone, F: Field> {
+    simplices: Vec<Vec<Vec<V>>>,
+    // Cocycles whose classes form a basis, per degree.
+    basis: Vec<Vec<Vec<F>>>,
+    // A basis of the coboundaries, per degree.
+    coboundaries: Vec<Vec<Vec<F>>>,
+}
+
+impl<V: Ord + Hash + Clone, F: Field> CohomologyRing<V, F> {
+    pub fn new(complex: &SimplicialComplex<V>) -> Self {
+        let top = complex.dimension().map_or(0, |d| d + 1);
+        let simplices: Vec<Vec<Vec<V>>> = (0..top).map(|p| complex.simplices_of_dim(p)).collect();
+        let mut basis = Vec::new();
+        let mut coboundaries = Vec::new();
+        for (degree, cells) in simplices.iter().enumerate() {
+            // The coboundary is the transpose of the boundary.
+            let cocycles = complex
+                .boundary_matrix::<F>(degree + 1)
+                .transpose()
+                .nullspace();
+            let exact = complex
+                .boundary_matrix::<F>(degree)
+                .transpose()
+                .column_basis();
+            basis.push(extend_basis(cells.len(), &exact, cocycles));
+            coboundaries.push(exact);
+        }
+        Self {
+            simplices,
+            basis,
+            coboundaries,
+        }
+    }
+
+    /// The dimension of the cohomology in the given degree.
+    pub fn betti(&self, degree: usize) -> usize {
+        self.basis.get(degree).map_or(0, |b| b.len())
+    }
+
+    /// Representative cocycles of the basis classes in the given degree.
+    pub fn basis(&self, degree: usize) -> Vec<Cochain<F>> {
+        self.basis
+            .get(degree)
+            .into_iter()
+            .flatten()
+            .map(|values| Cochain {
+                degree,
+                values: values.clone(),
+            })
+            .collect()
+    }
+
+    /// The `index`-th basis class in the given degree.
+    pub fn generator(&self, degree: usize, index: usize) -> CohomologyClass<F> {
+        let mut coordinates = vec![F::zero(); self.betti(degree)];
+        coordinates[index] = F::one();
+        CohomologyClass {
+            degree,
+            coordinates,
+        }
+    }
+
+    /// The class of a cocycle, or `None` if the cochain is not a cocycle.
+    pub fn class_of(&self, cochain: &Cochain<F>) -> Option<CohomologyClass<F>> {
+        let degree = cochain.degree;
+        let Some(basis) = self.basis.get(degree) else {
+            return Some(CohomologyClass {
+                degree,
+                coordinates: Vec::new(),
+            });
+        };
+        let mut columns = basis.clone();
+        columns.extend(self.coboundaries[degree].iter().cloned());
+        let solution =
+            Matrix::from_columns(self.simplices[degree].len(), &columns).solve(&cochain.values)?;
+        Some(CohomologyClass {
+            degree,
+            coordinates: solution[..basis.len()].to_vec(),
+        })
+    }
+
+    /// A cocycle representing the given class.
+    pub fn representative(&self, class: &CohomologyClass<F>) -> Cochain<F> {
+        let size = self.simplices.get(class.degree).map_or(0, |s| s.len());
+        let mut values = vec![F::zero(); size];
+        let basis = self.basis.get(class.degree).into_iter().flatten();
+        for (coefficient, cocycle) in class.coordinates.iter().zip(basis) {
+            for (value, x) in values.iter_mut().zip(cocycle) {
+                *value = *value + *coefficient * *x;
+            }
+        }
+        Cochain {
+            degree: class.degree,
+            values,
+        }
+    }
+
+    /// The cup product of two cochains via the Alexander-Whitney formula,
+    /// `(a ∪ b)[v_0, ..., v_{p+q}] = a[v_0, ..., v_p] * b[v_p, ..., v_{p+q}]`,
+    /// with the vertices of each simplex in increasing order.
+    pub fn cup(&self, a: &Cochain<F>, b: &Cochain<F>) -> Cochain<F> {
+        let degree = a.degree + b.degree;
+        let values = match self.simplices.get(degree) {
+            None => Vec::new(),
+            Some(simplices) => simplices
+                .iter()
+                .map(|simplex| {
+                    let front = self.value(a, &simplex[..=a.degree]);
+                    let back = self.value(b, &simplex[a.degree..]);
+                    front * back
+                })
+                .collect(),
+        };
+        Cochain { degree, values }
+    }
+
+    fn value(&self, cochain: &Cochain<F>, simplex: &[V]) -> F {
+        let index = self.simplices[cochain.degree]
+            .binary_search_by(|s| s.as_slice().cmp(simplex))
+            .unwrap();
+        cochain.values[index]
+    }
+
+    /// The product of two classes in the cohomology ring.
+    pub fn product(&self, a: &CohomologyClass<F>, b: &CohomologyClass<F>) -> CohomologyClass<F> {
+        let cup = self.cup(&self.representative(a), &self.representative(b));
+        self.class_of(&cup)
+            .expect("the cup product of cocycles is a cocycle")
+    }
+
+    /// The dimension of the span of all products of a degree `p` class with a
+    /// degree `q` class.
+    pub fn product_rank(&self, p: usize, q: usize) -> usize {
+        let products: Vec<Vec<F>> = (0..self.betti(p))
+            .flat_map(|i| (0..self.betti(q)).map(move |j| (i, j)))
+            .map(|(i, j)| {
+                self.product(&self.generator(p, i), &self.generator(q, j))
+                    .coordinates
+            })
+            .collect();
+        Matrix::from_columns(self.betti(p + q), &products).rank()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linear_algebra::{Rational, Z2};
+
+    // The 9 vertex triangulation of the torus.
+    fn torus() -> SimplicialComplex<usize> {
+        let v = |i: usize, j: usize| 3 * (i % 3) + (j % 3);
+        let mut triangles = Vec::new();
+        for i in 0..3 {
+            for j in 0..3 {
+                triangles.push(vec![v(i, j), v(i + 1, j), v(i + 1, j + 1)]);
+                triangles.push(vec![v(i, j), v(i, j + 1), v(i + 1, j + 1)]);
+            }
+        }
+        SimplicialComplex::from_simplices(triangles)
+    }
+
+    // A 2-sphere with two circles attached at vertex 0.
+    fn sphere_wedge_circles() -> SimplicialComplex<usize> {
+        SimplicialComplex::from_simplices(vec![
+            vec![0, 1, 2],
+            vec![0, 1, 3],
+            vec![0, 2, 3],
+            vec![1, 2, 3],
+            vec![0, 4],
+            vec![4, 5],
+            vec![0, 5],
+            vec![0, 6],
+            vec![6, 7],
+            vec![0, 7],
+        ])
+    }
+
+    #[test]
+    fn betti_numbers_agree() {
+        let torus = CohomologyRing::<_, Z2>::new(&torus());
+        let wedge = CohomologyRing::<_, Z2>::new(&sphere_wedge_circles());
+        for degree in 0..3 {
+            assert_eq!(torus.betti(degree), wedge.betti(degree));
+        }
+        assert_eq!((torus.betti(0), torus.betti(1), torus.betti(2)), (1, 2, 1));
+    }
+
+    #[test]
+    fn cup_products_distinguish_torus() {
+        let torus = CohomologyRing::<_, Z2>::new(&torus());
+        let wedge = CohomologyRing::<_, Z2>::new(&sphere_wedge_circles());
+        assert_eq!(torus.product_rank(1, 1), 1);
+        assert_eq!(wedge.product_rank(1, 1), 0);
+    }
+
+    #[test]
+    fn unit_and_graded_commutativity() {
+        let ring = CohomologyRing::<_, Rational>::new(&torus());
+        let unit = ring.generator(0, 0);
+        let a = ring.generator(1, 0);
+        let b = ring.generator(1, 1);
+        assert_eq!(ring.product(&unit, &a), a);
+
+        let ab = ring.product(&a, &b);
+        let ba = ring.product(&b, &a);
+        assert!(!ab.is_zero());
+        assert_eq!(
+            ab.coordinates,
+            ba.coordinates.iter().map(|x| -*x).collect::<Vec<_>>()
+        );
+        assert!(ring.product(&a, &a).is_zero());
+    }
+
+    #[test]
+    fn non_cocycles_have_no_class() {
+        let ring = CohomologyRing::<_, Z2>::new(&sphere_wedge_circles());
+        let mut values = vec![Z2(false); ring.simplices[0].len()];
+        values[0] = Z2(true);
+        assert!(ring.class_of(&Cochain { degree: 0, values }).is_none());
+    }
+
+    #[test]
+    fn products_past_the_top_dimension_vanish() {
+        let circle = SimplicialComplex::from_simplices(vec![vec![0, 1], vec![1, 2], vec![0, 2]]);
+        let ring = CohomologyRing::<_, Z2>::new(&circle);
+        let a = ring.generator(1, 0);
+        let aa = ring.product(&a, &a);
+        assert_eq!(aa.degree, 2);
+        assert!(aa.is_zero());
+        let aaa = ring.product(&aa, &a);
+        assert_eq!(aaa.degree, 3);
+        assert!(aaa.is_zero());
+        assert!(ring.representative(&aaa).values.is_empty());
+    }
+}
@@ -0,0 +1,165 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+/// A directed graph without multiple edges. The order of `vertices` is used to
+/// break ties, so results do not depend on hashing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DirectedGraph<V: Eq + Hash + Clone> {
+    pub vertices: Vec<V>,
+    pub edges: HashSet<(V, V)>,
+}
+
+impl<V: Eq + Hash + Clone> DirectedGraph<V> {
+    pub fn new(vertices: Vec<V>, edges: HashSet<(V, V)>) -> Self {
+        let known: HashSet<&V> = vertices.iter().collect();
+        assert!(known.len() == vertices.len(), "Vertices must be distinct");
+        assert!(
+            edges
+                .iter()
+                .all(|(a, b)| known.contains(a) && known.contains(b)),
+            "All edges must be between vertices",
+        );
+        Self { vertices, edges }
+    }
+
+    /// The heads of the edges leaving `vertex`, in vertex order.
+    pub fn successors(&self, vertex: &V) -> Vec<V> {
+        self.vertices
+            .iter()
+            .filter(|w| self.edges.contains(&(vertex.clone(), (*w).clone())))
+            .cloned()
+            .collect()
+    }
+
+    // Reachability by paths with at least one edge, as a matrix over the
+    // vertex indices.
+    fn reachability(&self) -> Vec<Vec<bool>> {
+        let n = self.vertices.len();
+        let index: HashMap<&V, usize> = self
+            .vertices
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (v, i))
+            .collect();
+        let mut reach = vec![vec![false; n]; n];
+        for (a, b) in &self.edges {
+            reach[index[a]][index[b]] = true;
+        }
+        // Warshall's algorithm.
+        for k in 0..n {
+            for i in 0..n {
+                if reach[i][k] {
+                    let through = reach[k].clone();
+                    for (r, t) in reach[i].iter_mut().zip(through) {
+                        *r |= t;
+                    }
+                }
+            }
+        }
+        reach
+    }
+
+    fn with_edges(&self, relation: &[Vec<bool>]) -> Self {
+        let mut edges = HashSet::new();
+        for (i, row) in relation.iter().enumerate() {
+            for (j, &related) in row.iter().enumerate() {
+                if related {
+                    edges.insert((self.vertices[i].clone(), self.vertices[j].clone()));
+                }
+            }
+        }
+        Self {
+            vertices: self.vertices.clone(),
+            edges,
+        }
+    }
+
+    /// The graph with an edge from `a` to `b` whenever there is a nonempty
+    /// path from `a` to `b`.
+    pub fn transitive_closure(&self) -> Self {
+        self.with_edges(&self.reachability())
+    }
+
+    /// The smallest graph with the same transitive closure. Only acyclic
+    /// graphs have a unique one.
+    pub fn transitive_reduction(&self) -> Self {
+        assert!(
+            self.topological_sort().is_some(),
+            "Only acyclic graphs have a transitive reduction"
+        );
+        let reach = self.reachability();
+        let n = self.vertices.len();
+        let reduced: Vec<Vec<bool>> = (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| reach[i][j] && !(0..n).any(|k| reach[i][k] && reach[k][j]))
+                    .collect()
+            })
+            .collect();
+        self.with_edges(&reduced)
+    }
+
+    /// Orders the vertices so that every edge points forward, or returns
+    /// `None` if the graph has a cycle. Among the vertices that are ready, the
+    /// earliest in `vertices` comes first.
+    pub fn topological_sort(&self) -> Option<Vec<V>> {
+        let mut in_degree: HashMap<&V, usize> = self.vertices.iter().map(|v| (v, 0)).collect();
+        for (a, b) in &self.edges {
+            if a != b {
+                *in_degree.get_mut(b).unwrap() += 1;
+            } else {
+                return None;
+            }
+        }
+        let mut order = Vec::new();
+        let mut done = vec![false; self.vertices.len()];
+        while order.len() < self.vertices.len() {
+            let next = (0..self.vertices.len())
+                .find(|&i| !done[i] && in_degree[&self.vertices[i]] == 0)?;
+            done[next] = true;
+            let vertex = &self.vertices[next];
+            for w in self.successors(vertex) {
+                *in_degree.get_mut(&w).unwrap() -= 1;
+            }
+            order.push(vertex.clone());
+        }
+        Some(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(n: usize, edges: &[(usize, usize)]) -> DirectedGraph<usize> {
+        DirectedGraph::new((0..n).collect(), edges.iter().cloned().collect())
+    }
+
+    #[test]
+    fn closure_and_reduction() {
+        let path = graph(4, &[(0, 1), (1, 2), (2, 3), (0, 2)]);
+        let closure = path.transitive_closure();
+        assert_eq!(closure.edges.len(), 6);
+        assert!(closure.edges.contains(&(0, 3)));
+        let reduction = path.transitive_reduction();
+        assert_eq!(reduction, graph(4, &[(0, 1), (1, 2), (2, 3)]));
+        assert_eq!(closure.transitive_reduction(), reduction);
+    }
+
+    #[test]
+    fn topological_sort() {
+        let diamond = graph(4, &[(3, 1), (3, 2), (1, 0), (2, 0)]);
+        assert_eq!(diamond.topological_sort(), Some(vec![3, 1, 2, 0]));
+        assert_eq!(diamond.successors(&3), vec![1, 2]);
+        assert_eq!(graph(2, &[(0, 1), (1, 0)]).topological_sort(), None);
+        assert_eq!(graph(1, &[(0, 0)]).topological_sort(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cycles_have_no_reduction() {
+        graph(2, &[(0, 1), (1, 0)]).transitive_reduction();
+    }
+}
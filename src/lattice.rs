@@ -0,0 +1,392 @@
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    hash::Hash,
+    rc::Rc,
+};
+
+use crate::{
+    alexandrov::{FinitePreOrderedSet, PreOrderedSet},
+    posets::{DivisorLattice, ProductOrder, SubsetLattice, TotalOrder},
+    topology::FiniteTopologicalSpace,
+};
+
+/// A finite partial order in which every pair of elements has a greatest lower
+/// bound and a least upper bound.
+///
+/// The provided methods search the elements, which works for any finite
+/// lattice. Implementors that know their operations should override them.
+pub trait Lattice: FinitePreOrderedSet {
+    /// The greatest lower bound of `a` and `b`.
+    fn meet(&self, a: Self::Element, b: Self::Element) -> Self::Element {
+        let lower: Vec<Self::Element> = self
+            .elements()
+            .into_iter()
+            .filter(|x| self.compare(x.clone(), a.clone()) && self.compare(x.clone(), b.clone()))
+            .collect();
+        lower
+            .iter()
+            .find(|x| lower.iter().all(|y| self.compare(y.clone(), (*x).clone())))
+            .cloned()
+            .expect("every pair of elements of a lattice has a meet")
+    }
+
+    /// The least upper bound of `a` and `b`.
+    fn join(&self, a: Self::Element, b: Self::Element) -> Self::Element {
+        let upper: Vec<Self::Element> = self
+            .elements()
+            .into_iter()
+            .filter(|x| self.compare(a.clone(), x.clone()) && self.compare(b.clone(), x.clone()))
+            .collect();
+        upper
+            .iter()
+            .find(|x| upper.iter().all(|y| self.compare((*x).clone(), y.clone())))
+            .cloned()
+            .expect("every pair of elements of a lattice has a join")
+    }
+
+    fn top(&self) -> Self::Element {
+        self.elements()
+            .into_iter()
+            .reduce(|a, b| self.join(a, b))
+            .expect("a lattice is nonempty")
+    }
+
+    fn bottom(&self) -> Self::Element {
+        self.elements()
+            .into_iter()
+            .reduce(|a, b| self.meet(a, b))
+            .expect("a lattice is nonempty")
+    }
+
+    /// The elements `b` with `a ∧ b = ⊥` and `a ∨ b = ⊤`.
+    fn complements(&self, a: Self::Element) -> Vec<Self::Element> {
+        let (top, bottom) = (self.top(), self.bottom());
+        self.elements()
+            .into_iter()
+            .filter(|b| {
+                self.meet(a.clone(), b.clone()) == bottom && self.join(a.clone(), b.clone()) == top
+            })
+            .collect()
+    }
+
+    /// Checks `a ∧ (b ∨ c) = (a ∧ b) ∨ (a ∧ c)` for all elements.
+    fn is_distributive(&self) -> bool {
+        triples(self.elements()).all(|(a, b, c)| {
+            self.meet(a.clone(), self.join(b.clone(), c.clone()))
+                == self.join(self.meet(a.clone(), b), self.meet(a, c))
+        })
+    }
+
+    /// Checks `a ∨ (b ∧ c) = (a ∨ b) ∧ c` whenever `a ≤ c`.
+    fn is_modular(&self) -> bool {
+        triples(self.elements())
+            .filter(|(a, _, c)| self.compare(a.clone(), c.clone()))
+            .all(|(a, b, c)| {
+                self.join(a.clone(), self.meet(b.clone(), c.clone()))
+                    == self.meet(self.join(a, b), c)
+            })
+    }
+}
+
+fn triples<T: Clone>(items: Vec<T>) -> impl Iterator<Item = (T, T, T)> {
+    let items = Rc::new(items);
+    (0..items.len()).flat_map(move |i| {
+        let items = items.clone();
+        (0..items.len()).flat_map(move |j| {
+            let items = items.clone();
+            (0..items.len()).map(move |k| (items[i].clone(), items[j].clone(), items[k].clone()))
+        })
+    })
+}
+
+impl<T: Ord + Hash + Clone> Lattice for SubsetLattice<T> {
+    fn meet(&self, a: BTreeSet<T>, b: BTreeSet<T>) -> BTreeSet<T> {
+        a.intersection(&b).cloned().collect()
+    }
+
+    fn join(&self, a: BTreeSet<T>, b: BTreeSet<T>) -> BTreeSet<T> {
+        a.union(&b).cloned().collect()
+    }
+
+    fn top(&self) -> BTreeSet<T> {
+        self.ground.clone()
+    }
+
+    fn bottom(&self) -> BTreeSet<T> {
+        BTreeSet::new()
+    }
+
+    fn complements(&self, a: BTreeSet<T>) -> Vec<BTreeSet<T>> {
+        vec![self.ground.difference(&a).cloned().collect()]
+    }
+}
+
+impl<T: Ord + Hash + Clone> Lattice for TotalOrder<T> {
+    fn meet(&self, a: T, b: T) -> T {
+        a.min(b)
+    }
+
+    fn join(&self, a: T, b: T) -> T {
+        a.max(b)
+    }
+
+    fn top(&self) -> T {
+        self.elements().pop().expect("a lattice is nonempty")
+    }
+
+    fn bottom(&self) -> T {
+        self.elements()
+            .into_iter()
+            .next()
+            .expect("a lattice is nonempty")
+    }
+}
+
+/// Meets are greatest common divisors and joins are least common multiples.
+impl Lattice for DivisorLattice {
+    fn meet(&self, a: u64, b: u64) -> u64 {
+        gcd(a, b)
+    }
+
+    fn join(&self, a: u64, b: u64) -> u64 {
+        a / gcd(a, b) * b
+    }
+
+    fn top(&self) -> u64 {
+        self.n()
+    }
+
+    fn bottom(&self) -> u64 {
+        1
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+impl<A: Lattice, B: Lattice> Lattice for ProductOrder<A, B> {
+    fn meet(&self, a: Self::Element, b: Self::Element) -> Self::Element {
+        (self.left.meet(a.0, b.0), self.right.meet(a.1, b.1))
+    }
+
+    fn join(&self, a: Self::Element, b: Self::Element) -> Self::Element {
+        (self.left.join(a.0, b.0), self.right.join(a.1, b.1))
+    }
+
+    fn top(&self) -> Self::Element {
+        (self.left.top(), self.right.top())
+    }
+
+    fn bottom(&self) -> Self::Element {
+        (self.left.bottom(), self.right.bottom())
+    }
+}
+
+/// The open sets of a finite space ordered by inclusion. Elements are indices
+/// into the list of open sets, which [`OpenSetLattice::open_set`] turns back
+/// into sets of points.
+///
+/// Meets are intersections and joins are unions, since finite intersections of
+/// open sets are open. The complemented elements are the clopen sets.
+pub struct OpenSetLattice<'a, X: FiniteTopologicalSpace> {
+    pub space: &'a X,
+    points: Vec<X::Point>,
+    // Membership of each point, per open set.
+    open_sets: Vec<Vec<bool>>,
+    index: HashMap<Vec<bool>, usize>,
+}
+
+impl<'a, X: FiniteTopologicalSpace> OpenSetLattice<'a, X> {
+    pub fn new(space: &'a X) -> Self {
+        let points: Vec<X::Point> = space.points().into_iter().collect();
+        let open_sets: Vec<Vec<bool>> = space
+            .open_sets()
+            .into_iter()
+            .map(|set| points.iter().map(|p| set.contains(p)).collect())
+            .collect();
+        let index = open_sets
+            .iter()
+            .enumerate()
+            .map(|(i, set)| (set.clone(), i))
+            .collect();
+        Self {
+            space,
+            points,
+            open_sets,
+            index,
+        }
+    }
+
+    pub fn open_set(&self, element: usize) -> HashSet<X::Point> {
+        self.points
+            .iter()
+            .zip(&self.open_sets[element])
+            .filter(|(_, member)| **member)
+            .map(|(p, _)| p.clone())
+            .collect()
+    }
+
+    /// The element for an open set, or `None` if the set is not open.
+    pub fn element_of(&self, set: &HashSet<X::Point>) -> Option<usize> {
+        if !set.iter().all(|p| self.points.contains(p)) {
+            return None;
+        }
+        let member: Vec<bool> = self.points.iter().map(|p| set.contains(p)).collect();
+        self.index.get(&member).copied()
+    }
+
+    fn combine(&self, a: usize, b: usize, op: impl Fn(bool, bool) -> bool) -> usize {
+        let member: Vec<bool> = self.open_sets[a]
+            .iter()
+            .zip(&self.open_sets[b])
+            .map(|(x, y)| op(*x, *y))
+            .collect();
+        self.index[&member]
+    }
+}
+
+impl<X: FiniteTopologicalSpace> PreOrderedSet for OpenSetLattice<'_, X> {
+    type Element = usize;
+
+    fn compare(&self, element_a: usize, element_b: usize) -> bool {
+        self.open_sets[element_a]
+            .iter()
+            .zip(&self.open_sets[element_b])
+            .all(|(a, b)| !a || *b)
+    }
+
+    fn upper_set(&self, element: usize) -> Vec<usize> {
+        (0..self.open_sets.len())
+            .filter(|&other| self.compare(element, other))
+            .collect()
+    }
+}
+
+impl<X: FiniteTopologicalSpace> FinitePreOrderedSet for OpenSetLattice<'_, X> {
+    fn elements(&self) -> Vec<usize> {
+        (0..self.open_sets.len()).collect()
+    }
+}
+
+impl<X: FiniteTopologicalSpace> Lattice for OpenSetLattice<'_, X> {
+    fn meet(&self, a: usize, b: usize) -> usize {
+        self.combine(a, b, |x, y| x && y)
+    }
+
+    fn join(&self, a: usize, b: usize) -> usize {
+        self.combine(a, b, |x, y| x || y)
+    }
+
+    fn top(&self) -> usize {
+        self.index[&vec![true; self.points.len()]]
+    }
+
+    fn bottom(&self) -> usize {
+        self.index[&vec![false; self.points.len()]]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{spaces::finite_topology::FiniteTopology, test_support::set};
+
+    // The pentagon N5 and the diamond M3 on 0..5, with 0 at the bottom and 4
+    // at the top.
+    struct SmallLattice {
+        // The pairs a < b other than those through the bottom or the top.
+        relations: Vec<(u8, u8)>,
+    }
+
+    impl PreOrderedSet for SmallLattice {
+        type Element = u8;
+        fn compare(&self, a: u8, b: u8) -> bool {
+            a == b || a == 0 || b == 4 || self.relations.contains(&(a, b))
+        }
+        fn upper_set(&self, a: u8) -> Vec<u8> {
+            (0..5).filter(|&b| self.compare(a, b)).collect()
+        }
+    }
+
+    impl FinitePreOrderedSet for SmallLattice {
+        fn elements(&self) -> Vec<u8> {
+            (0..5).collect()
+        }
+    }
+
+    impl Lattice for SmallLattice {}
+
+    #[test]
+    fn pentagon_and_diamond() {
+        let pentagon = SmallLattice {
+            relations: vec![(1, 2)],
+        };
+        assert_eq!(pentagon.join(1, 3), 4);
+        assert_eq!(pentagon.meet(2, 3), 0);
+        assert!(!pentagon.is_modular());
+        assert!(!pentagon.is_distributive());
+        assert_eq!(pentagon.complements(3), vec![1, 2]);
+
+        let diamond = SmallLattice { relations: vec![] };
+        assert!(diamond.is_modular());
+        assert!(!diamond.is_distributive());
+        assert_eq!((diamond.bottom(), diamond.top()), (0, 4));
+    }
+
+    #[test]
+    fn concrete_lattices() {
+        let subsets = SubsetLattice::new(vec![1, 2, 3]);
+        let a: BTreeSet<i32> = [1, 2].into_iter().collect();
+        let b: BTreeSet<i32> = [2, 3].into_iter().collect();
+        assert_eq!(
+            subsets.meet(a.clone(), b.clone()),
+            [2].into_iter().collect()
+        );
+        assert_eq!(subsets.join(a.clone(), b), subsets.top());
+        assert!(subsets.is_distributive());
+        assert_eq!(subsets.complements(a), vec![[3].into_iter().collect()]);
+
+        let divisors = DivisorLattice::new(12);
+        assert_eq!(divisors.meet(4, 6), 2);
+        assert_eq!(divisors.join(4, 6), 12);
+        assert_eq!((divisors.bottom(), divisors.top()), (1, 12));
+        assert!(divisors.is_distributive());
+        // 2 has no complement since 12 is not square free, but every divisor
+        // of 30 has one.
+        assert!(divisors.complements(2).is_empty());
+        assert_eq!(DivisorLattice::new(30).complements(6), vec![5]);
+
+        let grid = ProductOrder::new(TotalOrder::new(0..3), TotalOrder::new(0..3));
+        assert_eq!(grid.meet((0, 2), (1, 1)), (0, 1));
+        assert_eq!(grid.join((0, 2), (1, 1)), (1, 2));
+        assert_eq!(grid.top(), (2, 2));
+        assert!(grid.is_distributive());
+    }
+
+    #[test]
+    fn open_sets_form_a_distributive_lattice() {
+        let space = FiniteTopology::from_subbasis(
+            set(&['a', 'b', 'c']),
+            vec![set(&['a']), set(&['a', 'b']), set(&['c'])],
+        );
+        let lattice = OpenSetLattice::new(&space);
+        assert_eq!(lattice.elements().len(), space.open_sets().len());
+        let ab = lattice.element_of(&set(&['a', 'b'])).unwrap();
+        let c = lattice.element_of(&set(&['c'])).unwrap();
+        let a = lattice.element_of(&set(&['a'])).unwrap();
+        assert_eq!(lattice.join(ab, c), lattice.top());
+        assert_eq!(lattice.meet(ab, c), lattice.bottom());
+        assert_eq!(lattice.open_set(lattice.meet(ab, a)), set(&['a']));
+        assert!(lattice.compare(a, ab));
+        assert!(lattice.element_of(&set(&['b'])).is_none());
+        assert!(lattice.is_distributive());
+        // The clopen sets are the complemented ones.
+        assert_eq!(lattice.complements(ab), vec![c]);
+        assert!(lattice.complements(a).is_empty());
+    }
+}
@@ -1,4 +1,18 @@
 pub mod alexandrov;
+pub mod cohomology;
+pub mod directed_graph;
+pub mod lattice;
+pub mod linear_algebra;
 pub mod merkle;
+pub mod metrics;
+pub mod mobius;
+pub mod neighbors;
+pub mod persistence;
+pub mod posets;
+pub mod separation;
+pub mod sheaf;
 pub mod spaces;
+#[cfg(test)]
+mod test_support;
 pub mod topology;
+mod union_find;
@@ -0,0 +1,441 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+/// Trait for the coefficient fields used in (co)homology and linear sheaf
+/// computations.
+pub trait Field:
+    Copy
+    + Eq
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    fn zero() -> Self;
+    fn one() -> Self;
+    // The multiplicative inverse. Only called on nonzero elements.
+    fn inverse(self) -> Self;
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+}
+
+/// The field with two elements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Z2(pub bool);
+
+impl Add for Z2 {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Z2(self.0 != other.0)
+    }
+}
+
+impl Sub for Z2 {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Z2(self.0 != other.0)
+    }
+}
+
+impl Mul for Z2 {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        Z2(self.0 && other.0)
+    }
+}
+
+impl Neg for Z2 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self
+    }
+}
+
+impl Field for Z2 {
+    fn zero() -> Self {
+        Z2(false)
+    }
+    fn one() -> Self {
+        Z2(true)
+    }
+    fn inverse(self) -> Self {
+        assert!(self.0, "Zero has no inverse");
+        self
+    }
+}
+
+/// The rational numbers, kept in lowest terms with a positive denominator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    pub fn new(numerator: i128, denominator: i128) -> Self {
+        assert!(denominator != 0, "Denominator must be nonzero");
+        let divisor = gcd(numerator, denominator) * denominator.signum();
+        Self {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.max(1)
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Self::new(value.into(), 1)
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self::new(
+            self.numerator * other.denominator + other.numerator * self.denominator,
+            self.denominator * other.denominator,
+        )
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.numerator * other.numerator,
+            self.denominator * other.denominator,
+        )
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl Field for Rational {
+    fn zero() -> Self {
+        Self::new(0, 1)
+    }
+    fn one() -> Self {
+        Self::new(1, 1)
+    }
+    fn inverse(self) -> Self {
+        Self::new(self.denominator, self.numerator)
+    }
+}
+
+/// Selects the vectors of `candidates` that are linearly independent from
+/// `base` and from the previously selected candidates. When `base` spans a
+/// subspace of the span of `candidates`, the result is a basis of a
+/// complement of that subspace.
+pub fn extend_basis<F: Field>(dim: usize, base: &[Vec<F>], candidates: Vec<Vec<F>>) -> Vec<Vec<F>> {
+    let mut span = base.to_vec();
+    let mut rank = Matrix::from_columns(dim, &span).rank();
+    let mut extension = Vec::new();
+    for candidate in candidates {
+        span.push(candidate.clone());
+        let extended = Matrix::from_columns(dim, &span).rank();
+        if extended > rank {
+            rank = extended;
+            extension.push(candidate);
+        } else {
+            span.pop();
+        }
+    }
+    extension
+}
+
+/// A dense matrix over a field, stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Matrix<F: Field> {
+    rows: usize,
+    cols: usize,
+    data: Vec<F>,
+}
+
+impl<F: Field> Matrix<F> {
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            data: vec![F::zero(); rows * cols],
+        }
+    }
+
+    pub fn identity(n: usize) -> Self {
+        let mut matrix = Self::zeros(n, n);
+        for i in 0..n {
+            matrix.set(i, i, F::one());
+        }
+        matrix
+    }
+
+    pub fn from_rows(rows: Vec<Vec<F>>) -> Self {
+        let cols = rows.first().map_or(0, |r| r.len());
+        assert!(
+            rows.iter().all(|r| r.len() == cols),
+            "All rows must have the same length",
+        );
+        Self {
+            rows: rows.len(),
+            cols,
+            data: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    // The number of rows has to be given explicitly so that matrices without
+    // columns keep their shape.
+    pub fn from_columns(rows: usize, columns: &[Vec<F>]) -> Self {
+        let mut matrix = Self::zeros(rows, columns.len());
+        for (j, column) in columns.iter().enumerate() {
+            assert_eq!(column.len(), rows, "All columns must have length {rows}");
+            for (i, value) in column.iter().enumerate() {
+                matrix.set(i, j, *value);
+            }
+        }
+        matrix
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> F {
+        self.data[row * self.cols + col]
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: F) {
+        self.data[row * self.cols + col] = value;
+    }
+
+    pub fn column(&self, col: usize) -> Vec<F> {
+        (0..self.rows).map(|i| self.get(i, col)).collect()
+    }
+
+    pub fn columns(&self) -> Vec<Vec<F>> {
+        (0..self.cols).map(|j| self.column(j)).collect()
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut transpose = Self::zeros(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                transpose.set(j, i, self.get(i, j));
+            }
+        }
+        transpose
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.data.iter().all(|x| x.is_zero())
+    }
+
+    pub fn mul_vector(&self, vector: &[F]) -> Vec<F> {
+        assert_eq!(vector.len(), self.cols, "Dimension mismatch");
+        (0..self.rows)
+            .map(|i| (0..self.cols).fold(F::zero(), |acc, j| acc + self.get(i, j) * vector[j]))
+            .collect()
+    }
+
+    /// Places the columns of `other` to the right of the columns of `self`.
+    pub fn hstack(&self, other: &Self) -> Self {
+        assert_eq!(self.rows, other.rows, "Dimension mismatch");
+        let mut columns = self.columns();
+        columns.extend(other.columns());
+        Self::from_columns(self.rows, &columns)
+    }
+
+    /// Computes the reduced row echelon form, returning it together with the
+    /// pivot column of each nonzero row.
+    pub fn rref(&self) -> (Self, Vec<usize>) {
+        let mut matrix = self.clone();
+        let mut pivots = Vec::new();
+        let mut row = 0;
+        for col in 0..self.cols {
+            let Some(pivot) = (row..self.rows).find(|&i| !matrix.get(i, col).is_zero()) else {
+                continue;
+            };
+            matrix.swap_rows(row, pivot);
+            let inverse = matrix.get(row, col).inverse();
+            for j in 0..self.cols {
+                matrix.set(row, j, matrix.get(row, j) * inverse);
+            }
+            for i in 0..self.rows {
+                let factor = matrix.get(i, col);
+                if i != row && !factor.is_zero() {
+                    for j in 0..self.cols {
+                        matrix.set(i, j, matrix.get(i, j) - factor * matrix.get(row, j));
+                    }
+                }
+            }
+            pivots.push(col);
+            row += 1;
+            if row == self.rows {
+                break;
+            }
+        }
+        (matrix, pivots)
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        if a != b {
+            for j in 0..self.cols {
+                self.data.swap(a * self.cols + j, b * self.cols + j);
+            }
+        }
+    }
+
+    pub fn rank(&self) -> usize {
+        self.rref().1.len()
+    }
+
+    /// A basis of the kernel, one vector per free column.
+    pub fn nullspace(&self) -> Vec<Vec<F>> {
+        let (reduced, pivots) = self.rref();
+        (0..self.cols)
+            .filter(|c| !pivots.contains(c))
+            .map(|free| {
+                let mut vector = vec![F::zero(); self.cols];
+                vector[free] = F::one();
+                for (row, &pivot) in pivots.iter().enumerate() {
+                    vector[pivot] = -reduced.get(row, free);
+                }
+                vector
+            })
+            .collect()
+    }
+
+    /// A basis of the column space, chosen among the columns of the matrix.
+    pub fn column_basis(&self) -> Vec<Vec<F>> {
+        self.rref().1.into_iter().map(|c| self.column(c)).collect()
+    }
+
+    /// Finds some `x` with `self * x = b`, if one exists.
+    pub fn solve(&self, b: &[F]) -> Option<Vec<F>> {
+        assert_eq!(b.len(), self.rows, "Dimension mismatch");
+        let augmented = self.hstack(&Self::from_columns(self.rows, &[b.to_vec()]));
+        let (reduced, pivots) = augmented.rref();
+        if pivots.last() == Some(&self.cols) {
+            return None;
+        }
+        let mut solution = vec![F::zero(); self.cols];
+        for (row, &pivot) in pivots.iter().enumerate() {
+            solution[pivot] = reduced.get(row, self.cols);
+        }
+        Some(solution)
+    }
+}
+
+impl<F: Field> Mul for &Matrix<F> {
+    type Output = Matrix<F>;
+    fn mul(self, other: &Matrix<F>) -> Matrix<F> {
+        assert_eq!(self.cols, other.rows, "Dimension mismatch");
+        let mut product = Matrix::zeros(self.rows, other.cols);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                let a = self.get(i, k);
+                if a.is_zero() {
+                    continue;
+                }
+                for j in 0..other.cols {
+                    product.set(i, j, product.get(i, j) + a * other.get(k, j));
+                }
+            }
+        }
+        product
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn z2_matrix(rows: Vec<Vec<u8>>) -> Matrix<Z2> {
+        Matrix::from_rows(
+            rows.into_iter()
+                .map(|r| r.into_iter().map(|x| Z2(x == 1)).collect())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn z2_arithmetic() {
+        assert_eq!(Z2(true) + Z2(true), Z2(false));
+        assert_eq!(Z2(true) * Z2(true), Z2(true));
+        assert_eq!(-Z2(true), Z2(true));
+        assert_eq!(Z2(true).inverse(), Z2::one());
+    }
+
+    #[test]
+    fn rational_arithmetic() {
+        let half = Rational::new(2, 4);
+        assert_eq!(half, Rational::new(-1, -2));
+        assert_eq!(half + half, Rational::one());
+        assert_eq!(half - Rational::one(), Rational::new(-1, 2));
+        assert_eq!(Rational::new(-2, 3).inverse(), Rational::new(-3, 2));
+        assert_eq!(Rational::new(0, 5), Rational::zero());
+    }
+
+    #[test]
+    fn rank_and_nullspace() {
+        // Boundary of a triangle over Z/2.
+        let boundary = z2_matrix(vec![vec![1, 0, 1], vec![1, 1, 0], vec![0, 1, 1]]);
+        assert_eq!(boundary.rank(), 2);
+        let kernel = boundary.nullspace();
+        assert_eq!(kernel.len(), 1);
+        assert!(boundary.mul_vector(&kernel[0]).iter().all(|x| x.is_zero()));
+    }
+
+    #[test]
+    fn solve_systems() {
+        let matrix = z2_matrix(vec![vec![1, 1], vec![0, 1]]);
+        let solution = matrix.solve(&[Z2(false), Z2(true)]).unwrap();
+        assert_eq!(matrix.mul_vector(&solution), vec![Z2(false), Z2(true)]);
+        let singular = z2_matrix(vec![vec![1, 1], vec![1, 1]]);
+        assert!(singular.solve(&[Z2(true), Z2(false)]).is_none());
+    }
+
+    #[test]
+    fn products() {
+        let a = z2_matrix(vec![vec![1, 1], vec![0, 1]]);
+        assert_eq!(&a * &a, Matrix::identity(2));
+        assert_eq!(a.transpose().transpose(), a);
+    }
+}
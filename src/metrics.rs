@@ -0,0 +1,776 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    hash::Hash,
+};
+
+use crate::topology::{FiniteTopologicalSpace, MetricSpace};
+
+/// A distance function on values of type `T`. Finite samples of values become
+/// metric spaces through
+/// [`PointCloud`](crate::spaces::point_cloud::PointCloud).
+pub trait Metric<T: ?Sized> {
+    fn distance(&self, a: &T, b: &T) -> f64;
+}
+
+fn coordinates<'a, T: AsRef<[f64]>>(a: &'a T, b: &'a T) -> impl Iterator<Item = f64> + 'a {
+    let (a, b) = (a.as_ref(), b.as_ref());
+    assert_eq!(a.len(), b.len(), "Vectors must have the same dimension");
+    a.iter().zip(b).map(|(x, y)| (x - y).abs())
+}
+
+/// The L2 distance on vectors.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Euclidean;
+
+impl<T: AsRef<[f64]>> Metric<T> for Euclidean {
+    fn distance(&self, a: &T, b: &T) -> f64 {
+        coordinates(a, b).map(|d| d * d).sum::<f64>().sqrt()
+    }
+}
+
+/// The L1 distance on vectors.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Manhattan;
+
+impl<T: AsRef<[f64]>> Metric<T> for Manhattan {
+    fn distance(&self, a: &T, b: &T) -> f64 {
+        coordinates(a, b).sum()
+    }
+}
+
+/// The L∞ distance on vectors.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Chebyshev;
+
+impl<T: AsRef<[f64]>> Metric<T> for Chebyshev {
+    fn distance(&self, a: &T, b: &T) -> f64 {
+        coordinates(a, b).fold(0.0, f64::max)
+    }
+}
+
+/// The number of positions at which two sequences of the same length differ.
+/// Strings are compared by characters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Hamming;
+
+fn hamming<U: PartialEq>(
+    a: impl ExactSizeIterator<Item = U>,
+    b: impl ExactSizeIterator<Item = U>,
+) -> f64 {
+    assert_eq!(a.len(), b.len(), "Sequences must have the same length");
+    a.zip(b).filter(|(x, y)| x != y).count() as f64
+}
+
+impl<U: PartialEq> Metric<Vec<U>> for Hamming {
+    fn distance(&self, a: &Vec<U>, b: &Vec<U>) -> f64 {
+        hamming(a.iter(), b.iter())
+    }
+}
+
+impl Metric<String> for Hamming {
+    fn distance(&self, a: &String, b: &String) -> f64 {
+        let a: Vec<char> = a.chars().collect();
+        let b: Vec<char> = b.chars().collect();
+        hamming(a.iter(), b.iter())
+    }
+}
+
+/// The least number of insertions, deletions and substitutions turning one
+/// sequence into the other. Strings are compared by characters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Levenshtein;
+
+fn levenshtein<U: PartialEq>(a: &[U], b: &[U]) -> f64 {
+    // One row of the dynamic programming table at a time.
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, x) in a.iter().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, y) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(x != y);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()] as f64
+}
+
+impl<U: PartialEq> Metric<Vec<U>> for Levenshtein {
+    fn distance(&self, a: &Vec<U>, b: &Vec<U>) -> f64 {
+        levenshtein(a, b)
+    }
+}
+
+impl Metric<String> for Levenshtein {
+    fn distance(&self, a: &String, b: &String) -> f64 {
+        let a: Vec<char> = a.chars().collect();
+        let b: Vec<char> = b.chars().collect();
+        levenshtein(&a, &b)
+    }
+}
+
+/// One minus the size of the intersection over the size of the union. Two
+/// empty sets are at distance zero.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Jaccard;
+
+fn jaccard(common: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        1.0 - common as f64 / total as f64
+    }
+}
+
+impl<U: Eq + Hash> Metric<HashSet<U>> for Jaccard {
+    fn distance(&self, a: &HashSet<U>, b: &HashSet<U>) -> f64 {
+        let common = a.intersection(b).count();
+        jaccard(common, a.len() + b.len() - common)
+    }
+}
+
+impl<U: Ord> Metric<BTreeSet<U>> for Jaccard {
+    fn distance(&self, a: &BTreeSet<U>, b: &BTreeSet<U>) -> f64 {
+        let common = a.intersection(b).count();
+        jaccard(common, a.len() + b.len() - common)
+    }
+}
+
+/// The length of the shortest path along a sphere between two points given as
+/// `(latitude, longitude)` in degrees, computed with the haversine formula.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GreatCircle {
+    pub radius: f64,
+}
+
+impl GreatCircle {
+    /// The mean radius of the Earth, so that distances are in kilometers.
+    pub const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+    pub fn new(radius: f64) -> Self {
+        Self { radius }
+    }
+
+    pub fn earth() -> Self {
+        Self::new(Self::EARTH_RADIUS_KM)
+    }
+}
+
+impl Metric<(f64, f64)> for GreatCircle {
+    fn distance(&self, a: &(f64, f64), b: &(f64, f64)) -> f64 {
+        let (lat_a, lon_a) = (a.0.to_radians(), a.1.to_radians());
+        let (lat_b, lon_b) = (b.0.to_radians(), b.1.to_radians());
+        let h = ((lat_b - lat_a) / 2.0).sin().powi(2)
+            + lat_a.cos() * lat_b.cos() * ((lon_b - lon_a) / 2.0).sin().powi(2);
+        2.0 * self.radius * h.sqrt().min(1.0).asin()
+    }
+}
+
+/// A failure of one of the metric axioms, found by [`validate_metric`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum MetricViolation<P> {
+    /// A distance that is NaN or infinite, which the other axioms cannot be
+    /// checked against.
+    NonFinite {
+        a: P,
+        b: P,
+        distance: f64,
+    },
+    /// A point at nonzero distance from itself.
+    Identity {
+        point: P,
+        distance: f64,
+    },
+    /// Two distinct points at distance zero, which makes the distance only a
+    /// pseudometric.
+    Indiscernible {
+        a: P,
+        b: P,
+    },
+    Negative {
+        a: P,
+        b: P,
+        distance: f64,
+    },
+    Symmetry {
+        a: P,
+        b: P,
+        forward: f64,
+        backward: f64,
+    },
+    /// `d(a, c)` exceeds `d(a, b) + d(b, c)`.
+    Triangle {
+        a: P,
+        b: P,
+        c: P,
+        direct: f64,
+        detour: f64,
+    },
+}
+
+/// Checks the metric axioms on every point, pair and triple of a finite
+/// space, and returns every violation. Differences up to `tolerance` are
+/// accepted, to allow for rounding. If any distance is not finite, only those
+/// distances are reported.
+pub fn validate_metric<X: FiniteTopologicalSpace + MetricSpace<Distance = f64>>(
+    space: &X,
+    tolerance: f64,
+) -> Vec<MetricViolation<X::Point>> {
+    let points: Vec<X::Point> = space.points().into_iter().collect();
+    let d: Vec<Vec<f64>> = points
+        .iter()
+        .map(|a| {
+            points
+                .iter()
+                .map(|b| space.distance(a.clone(), b.clone()))
+                .collect()
+        })
+        .collect();
+    let mut violations = Vec::new();
+    for (i, a) in points.iter().enumerate() {
+        for (j, b) in points.iter().enumerate() {
+            if !d[i][j].is_finite() {
+                violations.push(MetricViolation::NonFinite {
+                    a: a.clone(),
+                    b: b.clone(),
+                    distance: d[i][j],
+                });
+            }
+        }
+    }
+    // Every comparison with NaN fails, so the axioms are only checked on
+    // finite distances.
+    if !violations.is_empty() {
+        return violations;
+    }
+    for (i, a) in points.iter().enumerate() {
+        if d[i][i].abs() > tolerance {
+            violations.push(MetricViolation::Identity {
+                point: a.clone(),
+                distance: d[i][i],
+            });
+        }
+        for (j, b) in points.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            if d[i][j] < -tolerance {
+                violations.push(MetricViolation::Negative {
+                    a: a.clone(),
+                    b: b.clone(),
+                    distance: d[i][j],
+                });
+            }
+            if i < j {
+                if d[i][j].abs() <= tolerance {
+                    violations.push(MetricViolation::Indiscernible {
+                        a: a.clone(),
+                        b: b.clone(),
+                    });
+                }
+                if (d[i][j] - d[j][i]).abs() > tolerance {
+                    violations.push(MetricViolation::Symmetry {
+                        a: a.clone(),
+                        b: b.clone(),
+                        forward: d[i][j],
+                        backward: d[j][i],
+                    });
+                }
+            }
+            for (k, c) in points.iter().enumerate() {
+                if k != i && k != j && d[i][k] > d[i][j] + d[j][k] + tolerance {
+                    violations.push(MetricViolation::Triangle {
+                        a: a.clone(),
+                        b: b.clone(),
+                        c: c.clone(),
+                        direct: d[i][k],
+                        detour: d[i][j] + d[j][k],
+                    });
+                }
+            }
+        }
+    }
+    violations
+}
+
+/// The Hausdorff distance between two sets of points in the same space: the
+/// furthest any point of one set is from the other set. It is infinite when
+/// exactly one of the sets is empty.
+pub fn hausdorff_distance<X: FiniteTopologicalSpace + MetricSpace<Distance = f64>>(
+    space: &X,
+    set_a: &HashSet<X::Point>,
+    set_b: &HashSet<X::Point>,
+) -> f64 {
+    let directed = |from: &HashSet<X::Point>, to: &HashSet<X::Point>| {
+        from.iter()
+            .map(|a| {
+                to.iter()
+                    .map(|b| space.distance(a.clone(), b.clone()))
+                    .fold(f64::INFINITY, f64::min)
+            })
+            .fold(0.0, f64::max)
+    };
+    directed(set_a, set_b).max(directed(set_b, set_a))
+}
+
+/// Bounds on the Gromov–Hausdorff distance between two finite metric spaces,
+/// found by [`gromov_hausdorff_bounds`].
+#[derive(Clone, Debug)]
+pub struct GromovHausdorffBounds<P, Q> {
+    pub lower: f64,
+    pub upper: f64,
+    /// A correspondence whose distortion is twice `upper`.
+    pub correspondence: Vec<(P, Q)>,
+}
+
+// The points of a space with their distances to each other and their
+// eccentricities, sorted by decreasing eccentricity.
+fn distance_table<X: FiniteTopologicalSpace + MetricSpace<Distance = f64>>(
+    space: &X,
+) -> (Vec<X::Point>, Vec<Vec<f64>>, Vec<f64>) {
+    let points: Vec<X::Point> = space.points().into_iter().collect();
+    let table: Vec<Vec<f64>> = points
+        .iter()
+        .map(|p| {
+            points
+                .iter()
+                .map(|q| space.distance(p.clone(), q.clone()))
+                .collect()
+        })
+        .collect();
+    let eccentricities: Vec<f64> = table
+        .iter()
+        .map(|row| row.iter().cloned().fold(0.0, f64::max))
+        .collect();
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    order.sort_by(|&a, &b| eccentricities[b].total_cmp(&eccentricities[a]));
+    (
+        order.iter().map(|&i| points[i].clone()).collect(),
+        order
+            .iter()
+            .map(|&i| order.iter().map(|&j| table[i][j]).collect())
+            .collect(),
+        order.iter().map(|&i| eccentricities[i]).collect(),
+    )
+}
+
+// The largest change in distance between two pairs of the correspondence.
+fn distortion(pairs: &[(usize, usize)], dx: &[Vec<f64>], dy: &[Vec<f64>]) -> f64 {
+    let mut worst: f64 = 0.0;
+    for &(x, y) in pairs {
+        for &(x2, y2) in pairs {
+            worst = worst.max((dx[x][x2] - dy[y][y2]).abs());
+        }
+    }
+    worst
+}
+
+// A space's distance table with the eccentricities of its points.
+type Table<'a> = (&'a [Vec<f64>], &'a [f64]);
+
+// Matches each point of X, in order, to the point of Y that distorts the
+// pairs chosen so far the least, preferring similar eccentricities. The first
+// point goes to `first` if it is given.
+fn greedy_matching(
+    (dx, ecc_x): Table,
+    (dy, ecc_y): Table,
+    fixed: &[(usize, usize)],
+    first: Option<usize>,
+) -> Vec<usize> {
+    let mut matched: Vec<(usize, usize)> = fixed.to_vec();
+    let mut matching = Vec::with_capacity(dx.len());
+    for x in 0..dx.len() {
+        let cost = |y: usize| {
+            let distortion = matched
+                .iter()
+                .map(|&(x2, y2)| (dx[x][x2] - dy[y][y2]).abs())
+                .fold(0.0, f64::max);
+            (distortion, (ecc_x[x] - ecc_y[y]).abs())
+        };
+        let best = match first {
+            Some(y) if x == 0 => y,
+            _ => {
+                (0..dy.len())
+                    .map(|y| (y, cost(y)))
+                    .min_by(|(_, a), (_, b)| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)))
+                    .unwrap()
+                    .0
+            }
+        };
+        matched.push((x, best));
+        matching.push(best);
+    }
+    matching
+}
+
+// A correspondence made of a greedy matching from X to Y that starts by
+// sending the first point to `first`, and a greedy matching back from Y.
+fn greedy_correspondence(x: Table, y: Table, first: usize) -> Vec<(usize, usize)> {
+    let forward: Vec<(usize, usize)> = greedy_matching(x, y, &[], Some(first))
+        .into_iter()
+        .enumerate()
+        .collect();
+    let flipped: Vec<(usize, usize)> = forward.iter().map(|&(x, y)| (y, x)).collect();
+    let mut seen: HashSet<(usize, usize)> = forward.iter().cloned().collect();
+    let mut pairs = forward;
+    for (y, x) in greedy_matching(y, x, &flipped, None)
+        .into_iter()
+        .enumerate()
+    {
+        if seen.insert((x, y)) {
+            pairs.push((x, y));
+        }
+    }
+    pairs
+}
+
+// How many partners of the first point of X the upper bound tries.
+const SEEDS: usize = 4;
+
+/// Estimates the Gromov–Hausdorff distance between two finite, nonempty
+/// metric spaces.
+///
+/// Every correspondence has to pair points whose eccentricities differ by at
+/// most its distortion, so half the Hausdorff distance between the sets of
+/// eccentricities is a lower bound. It is at least half the difference of the
+/// diameters. The upper bound is half the distortion of the best of a few
+/// correspondences built greedily in both directions, each starting from a
+/// partner of the most eccentric point of X with a similar eccentricity, or
+/// half the larger diameter if that is smaller. For spaces of `n` and `m`
+/// points this takes `O((n + m)^3)` time.
+pub fn gromov_hausdorff_bounds<X, Y>(
+    space_x: &X,
+    space_y: &Y,
+) -> GromovHausdorffBounds<X::Point, Y::Point>
+where
+    X: FiniteTopologicalSpace + MetricSpace<Distance = f64>,
+    Y: FiniteTopologicalSpace + MetricSpace<Distance = f64>,
+{
+    let (points_x, dx, ecc_x) = distance_table(space_x);
+    let (points_y, dy, ecc_y) = distance_table(space_y);
+    assert!(
+        !points_x.is_empty() && !points_y.is_empty(),
+        "Both spaces must be nonempty"
+    );
+    let directed = |from: &[f64], to: &[f64]| {
+        from.iter()
+            .map(|a| {
+                to.iter()
+                    .map(|b| (a - b).abs())
+                    .fold(f64::INFINITY, f64::min)
+            })
+            .fold(0.0, f64::max)
+    };
+    let lower = directed(&ecc_x, &ecc_y).max(directed(&ecc_y, &ecc_x)) / 2.0;
+
+    let mut seeds: Vec<usize> = (0..dy.len()).collect();
+    seeds.sort_by(|&a, &b| {
+        (ecc_x[0] - ecc_y[a])
+            .abs()
+            .total_cmp(&(ecc_x[0] - ecc_y[b]).abs())
+    });
+    seeds.truncate(SEEDS);
+    let (mut upper, mut pairs) = seeds
+        .into_iter()
+        .map(|first| {
+            let pairs = greedy_correspondence((&dx, &ecc_x), (&dy, &ecc_y), first);
+            (distortion(&pairs, &dx, &dy) / 2.0, pairs)
+        })
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .unwrap();
+    // Pairing everything with everything distorts by at most the larger
+    // diameter.
+    let diameter = ecc_x[0].max(ecc_y[0]);
+    if diameter / 2.0 < upper {
+        upper = diameter / 2.0;
+        pairs = (0..dx.len())
+            .flat_map(|x| (0..dy.len()).map(move |y| (x, y)))
+            .collect();
+    }
+    GromovHausdorffBounds {
+        lower,
+        upper,
+        correspondence: pairs
+            .into_iter()
+            .map(|(x, y)| (points_x[x].clone(), points_y[y].clone()))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::point_cloud::PointCloud;
+
+    fn close(a: f64, b: f64, tolerance: f64) -> bool {
+        (a - b).abs() <= tolerance
+    }
+
+    #[test]
+    fn vector_metrics() {
+        let (a, b) = (vec![0.0, 0.0, 0.0], vec![1.0, -2.0, 2.0]);
+        assert_eq!(Euclidean.distance(&a, &b), 3.0);
+        assert_eq!(Manhattan.distance(&a, &b), 5.0);
+        assert_eq!(Chebyshev.distance(&a, &b), 2.0);
+        assert_eq!(Euclidean.distance(&[3.0, 0.0], &[0.0, 4.0]), 5.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn dimensions_must_agree() {
+        Euclidean.distance(&vec![0.0], &vec![0.0, 1.0]);
+    }
+
+    #[test]
+    fn hamming() {
+        assert_eq!(
+            Hamming.distance(&"karolin".to_string(), &"kathrin".to_string()),
+            3.0
+        );
+        assert_eq!(
+            Hamming.distance(&vec![true, false, true], &vec![true, true, false]),
+            2.0
+        );
+    }
+
+    #[test]
+    fn levenshtein() {
+        let d = |a: &str, b: &str| Levenshtein.distance(&a.to_string(), &b.to_string());
+        assert_eq!(d("kitten", "sitting"), 3.0);
+        assert_eq!(d("", "abc"), 3.0);
+        assert_eq!(d("flaw", "lawn"), 2.0);
+        assert_eq!(d("same", "same"), 0.0);
+        assert_eq!(Levenshtein.distance(&vec![1, 2, 3], &vec![2, 3]), 1.0);
+    }
+
+    #[test]
+    fn jaccard() {
+        let a: HashSet<i32> = [1, 2, 3].into_iter().collect();
+        let b: HashSet<i32> = [2, 3, 4, 5].into_iter().collect();
+        assert!(close(Jaccard.distance(&a, &b), 0.6, 1e-12));
+        assert_eq!(
+            Jaccard.distance(&HashSet::<i32>::new(), &HashSet::new()),
+            0.0
+        );
+        let c: BTreeSet<char> = ['x'].into_iter().collect();
+        let d: BTreeSet<char> = ['y'].into_iter().collect();
+        assert_eq!(Jaccard.distance(&c, &d), 1.0);
+    }
+
+    // Squared distances on a line fail the triangle inequality.
+    struct Squared;
+
+    impl Metric<f64> for Squared {
+        fn distance(&self, a: &f64, b: &f64) -> f64 {
+            (a - b) * (a - b)
+        }
+    }
+
+    // Remembers one direction of travel.
+    struct Uphill;
+
+    impl Metric<f64> for Uphill {
+        fn distance(&self, a: &f64, b: &f64) -> f64 {
+            if b > a {
+                2.0 * (b - a)
+            } else {
+                a - b
+            }
+        }
+    }
+
+    // Undefined between distinct values.
+    struct Undefined;
+
+    impl Metric<f64> for Undefined {
+        fn distance(&self, a: &f64, b: &f64) -> f64 {
+            if a == b {
+                0.0
+            } else {
+                f64::NAN
+            }
+        }
+    }
+
+    #[test]
+    fn non_finite_distances_are_reported() {
+        let undefined = PointCloud::new(vec![0.0, 1.0], Undefined);
+        let violations = validate_metric(&undefined, 1e-9);
+        assert_eq!(violations.len(), 2);
+        assert!(violations.iter().all(|v| matches!(
+            v,
+            MetricViolation::NonFinite { a, b, distance } if a != b && distance.is_nan()
+        )));
+
+        let far = PointCloud::new(vec![vec![0.0], vec![f64::INFINITY]], Euclidean);
+        // Both directions are infinite, and the far point is at NaN from
+        // itself.
+        let violations = validate_metric(&far, 1e-9);
+        assert_eq!(violations.len(), 3);
+        assert!(violations
+            .iter()
+            .all(|v| matches!(v, MetricViolation::NonFinite { .. })));
+    }
+
+    #[test]
+    fn standard_metrics_validate() {
+        let cloud = PointCloud::new(
+            vec![
+                vec![0.0, 0.0],
+                vec![1.0, 0.0],
+                vec![0.3, 2.0],
+                vec![-1.0, 1.0],
+            ],
+            Euclidean,
+        );
+        assert!(validate_metric(&cloud, 1e-9).is_empty());
+        let words: Vec<String> = ["tree", "three", "there", "the"]
+            .iter()
+            .map(|w| w.to_string())
+            .collect();
+        assert!(validate_metric(&PointCloud::new(words, Levenshtein), 0.0).is_empty());
+    }
+
+    #[test]
+    fn violations_are_reported() {
+        let squared = PointCloud::new(vec![0.0, 1.0, 2.0], Squared);
+        let violations = validate_metric(&squared, 1e-9);
+        assert_eq!(violations.len(), 2);
+        assert!(violations.iter().all(|v| matches!(
+            v,
+            MetricViolation::Triangle { b: 1, direct, detour, .. } if *direct == 4.0 && *detour == 2.0
+        )));
+
+        let uphill = PointCloud::new(vec![0.0, 1.0], Uphill);
+        let violations = validate_metric(&uphill, 1e-9);
+        assert_eq!(violations.len(), 1);
+        assert!(matches!(violations[0], MetricViolation::Symmetry { .. }));
+
+        let repeated = PointCloud::new(vec![vec![1.0], vec![1.0]], Manhattan);
+        let violations = validate_metric(&repeated, 0.0);
+        assert_eq!(violations.len(), 1);
+        assert!(matches!(
+            violations[0],
+            MetricViolation::Indiscernible { a, b } if a + b == 1
+        ));
+    }
+
+    fn line(values: &[f64]) -> PointCloud<Vec<f64>, Euclidean> {
+        PointCloud::new(values.iter().map(|v| vec![*v]).collect(), Euclidean)
+    }
+
+    #[test]
+    fn hausdorff() {
+        let cloud = line(&[0.0, 1.0, 2.0, 3.5]);
+        let set = |points: &[usize]| points.iter().cloned().collect::<HashSet<usize>>();
+        assert_eq!(
+            hausdorff_distance(&cloud, &set(&[0, 1]), &set(&[0, 1, 2, 3])),
+            2.5
+        );
+        assert_eq!(
+            hausdorff_distance(&cloud, &set(&[0, 3]), &set(&[1, 2])),
+            1.5
+        );
+        assert_eq!(hausdorff_distance(&cloud, &set(&[2]), &set(&[2])), 0.0);
+        assert_eq!(hausdorff_distance(&cloud, &set(&[]), &set(&[])), 0.0);
+        assert_eq!(
+            hausdorff_distance(&cloud, &set(&[]), &set(&[1])),
+            f64::INFINITY
+        );
+    }
+
+    #[test]
+    fn gromov_hausdorff_of_isometric_spaces() {
+        let triangle = PointCloud::new(
+            vec![vec![0.0, 0.0], vec![2.0, 0.0], vec![0.0, 3.0]],
+            Euclidean,
+        );
+        let moved = PointCloud::new(
+            vec![vec![5.0, 5.0], vec![5.0, 7.0], vec![2.0, 5.0]],
+            Euclidean,
+        );
+        let bounds = gromov_hausdorff_bounds(&triangle, &moved);
+        assert_eq!(bounds.lower, 0.0);
+        assert!(close(bounds.upper, 0.0, 1e-12));
+        assert_eq!(bounds.correspondence.len(), 3);
+        for (a, b) in bounds.correspondence {
+            assert_eq!(triangle.values[a][0], moved.values[b][1] - 5.0);
+        }
+    }
+
+    #[test]
+    fn gromov_hausdorff_of_larger_spaces() {
+        let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % 10_000) as f64 / 10_000.0
+        };
+        let scatter: Vec<Vec<f64>> = (0..200).map(|_| vec![next(), next()]).collect();
+        // A rotation by a quarter turn and a shift.
+        let turned: Vec<Vec<f64>> = scatter.iter().map(|p| vec![3.0 - p[1], p[0]]).collect();
+        let sample: Vec<Vec<f64>> = scatter.iter().step_by(2).cloned().collect();
+        let (scatter, turned, sample) = (
+            PointCloud::new(scatter, Euclidean),
+            PointCloud::new(turned, Euclidean),
+            PointCloud::new(sample, Euclidean),
+        );
+
+        let bounds = gromov_hausdorff_bounds(&scatter, &turned);
+        assert!(close(bounds.lower, 0.0, 1e-12));
+        assert!(close(bounds.upper, 0.0, 1e-12));
+
+        let bounds = gromov_hausdorff_bounds(&scatter, &sample);
+        assert!(bounds.lower <= bounds.upper);
+        // Half the points of the square are still spread across it.
+        assert!(bounds.upper < 0.25, "upper bound {}", bounds.upper);
+        let xs: HashSet<usize> = bounds.correspondence.iter().map(|&(x, _)| x).collect();
+        let ys: HashSet<usize> = bounds.correspondence.iter().map(|&(_, y)| y).collect();
+        assert_eq!((xs.len(), ys.len()), (200, 100));
+    }
+
+    #[test]
+    fn gromov_hausdorff_bounds_bracket() {
+        // A point against a segment is exactly half the segment's length away.
+        let bounds = gromov_hausdorff_bounds(&line(&[0.0]), &line(&[0.0, 4.0]));
+        assert_eq!(bounds.lower, 2.0);
+        assert_eq!(bounds.upper, 2.0);
+
+        let coarse = line(&[0.0, 2.0, 4.0]);
+        let fine = line(&[0.0, 1.0, 2.0, 3.0, 4.5]);
+        let bounds = gromov_hausdorff_bounds(&coarse, &fine);
+        assert!(bounds.lower <= bounds.upper);
+        assert!(bounds.lower >= 0.25);
+        // Sampling the same segment more finely moves it by at most the gaps.
+        assert!(bounds.upper <= 0.5);
+        let dx = |a: usize, b: usize| coarse.distance(a, b);
+        let dy = |a: usize, b: usize| fine.distance(a, b);
+        let distortion = bounds
+            .correspondence
+            .iter()
+            .flat_map(|&(x, y)| {
+                bounds
+                    .correspondence
+                    .iter()
+                    .map(move |&(x2, y2)| (dx(x, x2) - dy(y, y2)).abs())
+            })
+            .fold(0.0, f64::max);
+        assert!(close(distortion, 2.0 * bounds.upper, 1e-12));
+    }
+
+    #[test]
+    fn great_circle() {
+        let earth = GreatCircle::earth();
+        let paris = (48.8566, 2.3522);
+        let new_york = (40.7128, -74.0060);
+        assert!(close(earth.distance(&paris, &new_york), 5837.0, 5.0));
+        assert_eq!(earth.distance(&paris, &paris), 0.0);
+        // Antipodal points are half a circumference apart.
+        let unit = GreatCircle::new(1.0);
+        assert!(close(
+            unit.distance(&(0.0, 0.0), &(0.0, 180.0)),
+            std::f64::consts::PI,
+            1e-12
+        ));
+    }
+}
@@ -0,0 +1,247 @@
+use std::{collections::HashMap, hash::Hash};
+
+use crate::alexandrov::FinitePreOrderedSet;
+
+/// The Möbius function of a finite partial order, together with its zeta
+/// function.
+///
+/// Both are stored as matrices indexed by the elements in the order of a
+/// linear extension, which makes them upper triangular. The Möbius matrix is
+/// the inverse of the zeta matrix, and `mu(x, y)` is zero unless `x ≤ y`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MobiusFunction<E: Clone + Eq + Hash> {
+    elements: Vec<E>,
+    index: HashMap<E, usize>,
+    zeta: Vec<Vec<i64>>,
+    mobius: Vec<Vec<i64>>,
+}
+
+impl<E: Clone + Eq + Hash> MobiusFunction<E> {
+    /// Computes `mu` by the recursion `mu(x, x) = 1` and
+    /// `mu(x, y) = -Σ mu(x, z)` over `x ≤ z < y`. The order must be
+    /// antisymmetric.
+    pub fn new<O: FinitePreOrderedSet<Element = E>>(order: &O) -> Self {
+        let elements = order.linear_extension();
+        let n = elements.len();
+        let index: HashMap<E, usize> = elements
+            .iter()
+            .enumerate()
+            .map(|(i, e)| (e.clone(), i))
+            .collect();
+        let mut zeta = vec![vec![0; n]; n];
+        for (i, x) in elements.iter().enumerate() {
+            for y in order.upper_set(x.clone()) {
+                let j = index[&y];
+                assert!(i <= j, "The Möbius function needs an antisymmetric order");
+                zeta[i][j] = 1;
+            }
+        }
+        let mut mobius = vec![vec![0; n]; n];
+        for i in 0..n {
+            mobius[i][i] = 1;
+            for j in i + 1..n {
+                if zeta[i][j] == 1 {
+                    mobius[i][j] = -(i..j)
+                        .filter(|&k| zeta[k][j] == 1)
+                        .map(|k| mobius[i][k])
+                        .sum::<i64>();
+                }
+            }
+        }
+        Self {
+            elements,
+            index,
+            zeta,
+            mobius,
+        }
+    }
+
+    /// The elements in the order used for the rows and columns of the
+    /// matrices.
+    pub fn elements(&self) -> &[E] {
+        &self.elements
+    }
+
+    pub fn mu(&self, x: &E, y: &E) -> i64 {
+        self.mobius[self.index[x]][self.index[y]]
+    }
+
+    pub fn zeta_matrix(&self) -> &[Vec<i64>] {
+        &self.zeta
+    }
+
+    pub fn mobius_matrix(&self) -> &[Vec<i64>] {
+        &self.mobius
+    }
+
+    /// `g(x) = Σ f(y)` over `y ≥ x`. Missing values of `f` count as zero.
+    pub fn sum_above(&self, f: &HashMap<E, i64>) -> HashMap<E, i64> {
+        self.transform(f, &self.zeta, false)
+    }
+
+    /// `g(y) = Σ f(x)` over `x ≤ y`. Missing values of `f` count as zero.
+    pub fn sum_below(&self, f: &HashMap<E, i64>) -> HashMap<E, i64> {
+        self.transform(f, &self.zeta, true)
+    }
+
+    /// Recovers `f` from `g = sum_above(f)`, as
+    /// `f(x) = Σ mu(x, y) g(y)` over `y ≥ x`.
+    pub fn invert_sum_above(&self, g: &HashMap<E, i64>) -> HashMap<E, i64> {
+        self.transform(g, &self.mobius, false)
+    }
+
+    /// Recovers `f` from `g = sum_below(f)`, as
+    /// `f(y) = Σ mu(x, y) g(x)` over `x ≤ y`.
+    pub fn invert_sum_below(&self, g: &HashMap<E, i64>) -> HashMap<E, i64> {
+        self.transform(g, &self.mobius, true)
+    }
+
+    // Multiplies by the matrix, or by its transpose when summing from below.
+    fn transform(&self, f: &HashMap<E, i64>, matrix: &[Vec<i64>], below: bool) -> HashMap<E, i64> {
+        let values: Vec<i64> = self
+            .elements
+            .iter()
+            .map(|e| f.get(e).copied().unwrap_or(0))
+            .collect();
+        let n = self.elements.len();
+        self.elements
+            .iter()
+            .enumerate()
+            .map(|(i, e)| {
+                let sum = (0..n)
+                    .map(|j| {
+                        let weight = if below { matrix[j][i] } else { matrix[i][j] };
+                        weight * values[j]
+                    })
+                    .sum();
+                (e.clone(), sum)
+            })
+            .collect()
+    }
+
+    /// `mu(0, 1)` for the order with a new bottom `0` and top `1` adjoined. By
+    /// Hall's theorem this is the reduced Euler characteristic of the order
+    /// complex, whose simplices are the chains of the order.
+    pub fn reduced_euler_characteristic(&self) -> i64 {
+        // mu(0, x) for the adjoined bottom, in the order of `elements`.
+        let n = self.elements.len();
+        let mut from_bottom: Vec<i64> = Vec::with_capacity(n);
+        for j in 0..n {
+            let below: i64 = (0..j)
+                .filter(|&k| self.zeta[k][j] == 1)
+                .map(|k| from_bottom[k])
+                .sum();
+            from_bottom.push(-1 - below);
+        }
+        -1 - from_bottom.iter().sum::<i64>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        posets::{Divisibility, FacePoset, SubsetLattice, TotalOrder},
+        spaces::simplicial_complex::SimplicialComplex,
+    };
+
+    // The number theoretic Möbius function.
+    fn number_theoretic(n: u64) -> i64 {
+        let mut n = n;
+        let mut sign = 1;
+        let mut p = 2;
+        while p * p <= n {
+            if n.is_multiple_of(p) {
+                n /= p;
+                if n.is_multiple_of(p) {
+                    return 0;
+                }
+                sign = -sign;
+            }
+            p += 1;
+        }
+        if n > 1 {
+            -sign
+        } else {
+            sign
+        }
+    }
+
+    #[test]
+    fn divisibility_gives_classical_mobius() {
+        let mobius = MobiusFunction::new(&Divisibility::up_to(30));
+        for n in 1..=30 {
+            assert_eq!(mobius.mu(&1, &n), number_theoretic(n));
+        }
+        assert_eq!(mobius.mu(&2, &8), 0);
+        assert_eq!(mobius.mu(&2, &12), 1);
+        assert_eq!(mobius.mu(&5, &30), 1);
+        assert_eq!(mobius.mu(&3, &10), 0);
+    }
+
+    #[test]
+    fn subsets_alternate() {
+        let mobius = MobiusFunction::new(&SubsetLattice::new(vec![0, 1, 2, 3]));
+        let empty = Default::default();
+        for s in mobius.elements().to_vec() {
+            let sign = if s.len() % 2 == 0 { 1 } else { -1 };
+            assert_eq!(mobius.mu(&empty, &s), sign);
+        }
+    }
+
+    #[test]
+    fn mobius_inverts_zeta() {
+        let mobius = MobiusFunction::new(&Divisibility::divisors_of(60));
+        let n = mobius.elements().len();
+        for i in 0..n {
+            for j in 0..n {
+                let product: i64 = (0..n)
+                    .map(|k| mobius.zeta_matrix()[i][k] * mobius.mobius_matrix()[k][j])
+                    .sum();
+                assert_eq!(product, if i == j { 1 } else { 0 });
+            }
+        }
+    }
+
+    #[test]
+    fn inclusion_exclusion() {
+        // Sensors 0, 1 and 2 cover regions, and f counts the points covered by
+        // exactly the sensors of a set. Summing above gives the points covered
+        // by at least those sensors, which inversion undoes.
+        let lattice = SubsetLattice::new(vec![0, 1, 2]);
+        let mobius = MobiusFunction::new(&lattice);
+        let f: HashMap<_, i64> = mobius
+            .elements()
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (s.clone(), i as i64 + 1))
+            .collect();
+        let above = mobius.sum_above(&f);
+        assert_eq!(above[&Default::default()], (1..=8).sum());
+        assert_eq!(mobius.invert_sum_above(&above), f);
+        let below = mobius.sum_below(&f);
+        assert_eq!(mobius.invert_sum_below(&below), f);
+    }
+
+    #[test]
+    fn euler_characteristic_of_face_posets() {
+        let circle = SimplicialComplex::from_simplices(vec![vec![0, 1], vec![1, 2], vec![0, 2]]);
+        let sphere = SimplicialComplex::from_simplices(vec![
+            vec![0, 1, 2],
+            vec![0, 1, 3],
+            vec![0, 2, 3],
+            vec![1, 2, 3],
+        ]);
+        let disk = SimplicialComplex::from_simplices(vec![vec![0, 1, 2], vec![2, 3]]);
+        for complex in [circle, sphere, disk] {
+            let mobius = MobiusFunction::new(&FacePoset::from_simplicial_complex(&complex));
+            assert_eq!(
+                mobius.reduced_euler_characteristic(),
+                complex.euler_characteristic() - 1
+            );
+        }
+        // A chain is contractible.
+        let chain = MobiusFunction::new(&TotalOrder::new(0..5));
+        assert_eq!(chain.reduced_euler_characteristic(), 0);
+    }
+}
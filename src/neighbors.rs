@@ -0,0 +1,261 @@
+use std::cmp::Ordering;
+
+use crate::topology::{FiniteTopologicalSpace, MetricSpace};
+
+/// A vantage-point tree over the points of a finite metric space.
+///
+/// Every node splits the remaining points at the median distance from its
+/// vantage point. Queries use the triangle inequality to skip subtrees that
+/// cannot contain a close enough point, so they only need `distance` calls and
+/// usually far fewer than one per point. Building takes `O(n log n)` of them.
+pub struct VpTree<'a, X: FiniteTopologicalSpace + MetricSpace<Distance = f64>> {
+    pub space: &'a X,
+    nodes: Vec<Node<X::Point>>,
+    root: Option<usize>,
+}
+
+struct Node<P> {
+    vantage: P,
+    // Points in `inside` are at most this far from the vantage point, and
+    // points in `outside` are at least this far.
+    radius: f64,
+    inside: Option<usize>,
+    outside: Option<usize>,
+}
+
+fn by_distance<P>(a: &(P, f64), b: &(P, f64)) -> Ordering {
+    a.1.total_cmp(&b.1)
+}
+
+impl<'a, X: FiniteTopologicalSpace + MetricSpace<Distance = f64>> VpTree<'a, X> {
+    pub fn new(space: &'a X) -> Self {
+        let mut tree = Self {
+            space,
+            nodes: Vec::new(),
+            root: None,
+        };
+        tree.root = tree.build(space.points().into_iter().collect());
+        tree
+    }
+
+    fn build(&mut self, mut points: Vec<X::Point>) -> Option<usize> {
+        let vantage = points.pop()?;
+        let mut rest: Vec<(X::Point, f64)> = points
+            .into_iter()
+            .map(|p| {
+                let d = self.space.distance(vantage.clone(), p.clone());
+                (p, d)
+            })
+            .collect();
+        let mid = rest.len() / 2;
+        let radius = if rest.is_empty() {
+            0.0
+        } else {
+            rest.select_nth_unstable_by(mid, by_distance);
+            rest[mid].1
+        };
+        let outside: Vec<X::Point> = rest.split_off(mid).into_iter().map(|(p, _)| p).collect();
+        let inside: Vec<X::Point> = rest.into_iter().map(|(p, _)| p).collect();
+        let index = self.nodes.len();
+        self.nodes.push(Node {
+            vantage,
+            radius,
+            inside: None,
+            outside: None,
+        });
+        self.nodes[index].inside = self.build(inside);
+        self.nodes[index].outside = self.build(outside);
+        Some(index)
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// The `k` points closest to `query`, nearest first, with their distances.
+    /// Ties are broken arbitrarily.
+    pub fn nearest(&self, query: X::Point, k: usize) -> Vec<(X::Point, f64)> {
+        let mut found = Vec::with_capacity(k + 1);
+        if k > 0 {
+            self.search_nearest(self.root, &query, k, &mut found);
+        }
+        found
+    }
+
+    fn search_nearest(
+        &self,
+        node: Option<usize>,
+        query: &X::Point,
+        k: usize,
+        found: &mut Vec<(X::Point, f64)>,
+    ) {
+        let Some(index) = node else {
+            return;
+        };
+        let node = &self.nodes[index];
+        let d = self.space.distance(query.clone(), node.vantage.clone());
+        if found.len() < k || d < found[k - 1].1 {
+            let at = found.partition_point(|(_, e)| *e <= d);
+            found.insert(at, (node.vantage.clone(), d));
+            found.truncate(k);
+        }
+        // The current k-th distance, which only shrinks as the search goes on.
+        let bound = |found: &Vec<(X::Point, f64)>| {
+            if found.len() < k {
+                f64::INFINITY
+            } else {
+                found[k - 1].1
+            }
+        };
+        // Search the side the query falls in first, as it is the likelier to
+        // tighten the bound.
+        if d < node.radius {
+            self.search_nearest(node.inside, query, k, found);
+            if d + bound(found) >= node.radius {
+                self.search_nearest(node.outside, query, k, found);
+            }
+        } else {
+            self.search_nearest(node.outside, query, k, found);
+            if d - bound(found) <= node.radius {
+                self.search_nearest(node.inside, query, k, found);
+            }
+        }
+    }
+
+    /// All points at distance at most `radius` from `query`, nearest first.
+    pub fn within(&self, query: X::Point, radius: f64) -> Vec<(X::Point, f64)> {
+        let mut found = Vec::new();
+        let mut stack: Vec<usize> = self.root.into_iter().collect();
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            let d = self.space.distance(query.clone(), node.vantage.clone());
+            if d <= radius {
+                found.push((node.vantage.clone(), d));
+            }
+            if d - radius <= node.radius {
+                stack.extend(node.inside);
+            }
+            if d + radius >= node.radius {
+                stack.extend(node.outside);
+            }
+        }
+        found.sort_by(by_distance);
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+    use crate::{
+        metrics::{Euclidean, Levenshtein, Metric},
+        spaces::point_cloud::PointCloud,
+    };
+
+    // A deterministic scatter of points in the unit square.
+    fn scatter(n: usize) -> Vec<Vec<f64>> {
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % 10_000) as f64 / 10_000.0
+        };
+        (0..n).map(|_| vec![next(), next()]).collect()
+    }
+
+    fn brute_force<X: MetricSpace<Distance = f64, Point = usize>>(
+        space: &X,
+        query: usize,
+    ) -> Vec<f64> {
+        let mut distances: Vec<f64> = space
+            .points()
+            .into_iter()
+            .map(|p| space.distance(query, p))
+            .collect();
+        distances.sort_by(f64::total_cmp);
+        distances
+    }
+
+    fn distances(found: &[(usize, f64)]) -> Vec<f64> {
+        found.iter().map(|(_, d)| *d).collect()
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let cloud = PointCloud::new(scatter(200), Euclidean);
+        let tree = VpTree::new(&cloud);
+        assert_eq!(tree.len(), 200);
+        for query in [0, 17, 99, 150] {
+            let all = brute_force(&cloud, query);
+            let nearest = tree.nearest(query, 5);
+            assert_eq!(nearest[0], (query, 0.0));
+            assert_eq!(distances(&nearest), all[..5]);
+            let within = tree.within(query, 0.2);
+            let expected: Vec<f64> = all.iter().cloned().filter(|d| *d <= 0.2).collect();
+            assert_eq!(distances(&within), expected);
+            for (p, d) in within {
+                assert_eq!(cloud.distance(query, p), d);
+            }
+        }
+        assert_eq!(tree.nearest(3, 500).len(), 200);
+        assert!(tree.nearest(3, 0).is_empty());
+    }
+
+    #[test]
+    fn words() {
+        let words: Vec<String> = [
+            "sheaf", "shelf", "shell", "stalk", "stack", "shelve", "sheave",
+        ]
+        .iter()
+        .map(|w| w.to_string())
+        .collect();
+        let cloud = PointCloud::new(words, Levenshtein);
+        let tree = VpTree::new(&cloud);
+        let close: Vec<&str> = tree
+            .within(0, 2.0)
+            .into_iter()
+            .map(|(p, _)| cloud.values[p].as_str())
+            .collect();
+        assert_eq!(close[0], "sheaf");
+        assert_eq!(close[1], "shelf");
+        assert_eq!(close.len(), 4);
+        assert!(close.contains(&"shell") && close.contains(&"sheave"));
+    }
+
+    // Counts the distances a query computes.
+    struct Counting<'a> {
+        calls: &'a Cell<usize>,
+    }
+
+    impl Metric<Vec<f64>> for Counting<'_> {
+        fn distance(&self, a: &Vec<f64>, b: &Vec<f64>) -> f64 {
+            self.calls.set(self.calls.get() + 1);
+            Euclidean.distance(a, b)
+        }
+    }
+
+    #[test]
+    fn queries_prune() {
+        let calls = Cell::new(0);
+        let cloud = PointCloud::new(scatter(1000), Counting { calls: &calls });
+        let tree = VpTree::new(&cloud);
+        calls.set(0);
+        tree.nearest(42, 3);
+        assert!(calls.get() < 500, "{} distance calls", calls.get());
+    }
+
+    #[test]
+    fn empty_space() {
+        let cloud: PointCloud<Vec<f64>, Euclidean> = PointCloud::new(vec![], Euclidean);
+        let tree = VpTree::new(&cloud);
+        assert!(tree.is_empty());
+        assert!(tree.within(0, 1.0).is_empty());
+    }
+}
@@ -0,0 +1,348 @@
+use std::hash::Hash;
+
+use crate::{
+    linear_algebra::{extend_basis, Field, Matrix},
+    spaces::simplicial_complex::SimplicialComplex,
+};
+
+/// The direction of the inclusion between two consecutive complexes of a
+/// zigzag filtration.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    // K_i is included in K_{i + 1}.
+    Forward,
+    // K_{i + 1} is included in K_i.
+    Backward,
+}
+
+/// A homology class that is alive exactly on the complexes `birth..=death`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub dim: usize,
+    pub birth: usize,
+    pub death: usize,
+}
+
+/// A sequence of simplicial complexes K_0, K_1, ..., K_n where each step either
+/// adds or removes simplices, so consecutive complexes are related by an
+/// inclusion in one direction or the other.
+pub struct ZigzagFiltration<V: Ord + Hash + Clone> {
+    complexes: Vec<SimplicialComplex<V>>,
+    directions: Vec<Direction>,
+}
+
+impl<V: Ord + Hash + Clone> ZigzagFiltration<V> {
+    pub fn new(initial: SimplicialComplex<V>) -> Self {
+        Self {
+            complexes: vec![initial],
+            directions: Vec::new(),
+        }
+    }
+
+    // Appends the current complex with `simplex` and all of its faces added.
+    pub fn add(&mut self, simplex: Vec<V>) {
+        let mut next = self.complexes.last().unwrap().clone();
+        next.add_simplex(simplex);
+        self.complexes.push(next);
+        self.directions.push(Direction::Forward);
+    }
+
+    // Appends the current complex with `simplex` and all of its cofaces
+    // removed.
+    pub fn remove(&mut self, simplex: &[V]) {
+        let mut next = self.complexes.last().unwrap().clone();
+        next.remove_simplex(simplex);
+        self.complexes.push(next);
+        self.directions.push(Direction::Backward);
+    }
+
+    pub fn complexes(&self) -> &[SimplicialComplex<V>] {
+        &self.complexes
+    }
+
+    pub fn directions(&self) -> &[Direction] {
+        &self.directions
+    }
+
+    /// Computes the zigzag barcode with coefficients in `F`.
+    ///
+    /// The homology of every complex and the maps induced by the inclusions
+    /// are computed first. The number of bars containing a range of indices is
+    /// the rank of the map from the limit to the colimit of the zigzag module
+    /// restricted to that range, and the bars are recovered from these ranks
+    /// by inclusion-exclusion.
+    pub fn barcode<F: Field>(&self) -> Vec<Interval> {
+        let union = SimplicialComplex::from_simplices(
+            self.complexes
+                .iter()
+                .flat_map(|k| k.simplices().cloned().collect::<Vec<_>>()),
+        );
+        let mut barcode = Vec::new();
+        let Some(top) = union.dimension() else {
+            return barcode;
+        };
+        for dim in 0..=top {
+            let module = ZigzagModule::<F>::homology(self, &union, dim);
+            barcode.extend(
+                module
+                    .intervals()
+                    .into_iter()
+                    .map(|(birth, death)| Interval { dim, birth, death }),
+            );
+        }
+        barcode.sort();
+        barcode
+    }
+}
+
+// A zigzag diagram of finite dimensional vector spaces. `maps[i]` goes from
+// space `i` to space `i + 1` when going forward, and the other way otherwise.
+struct ZigzagModule<F: Field> {
+    dims: Vec<usize>,
+    directions: Vec<Direction>,
+    maps: Vec<Matrix<F>>,
+}
+
+// Homology representatives of a single complex, as chains on the union.
+struct HomologyBasis<F: Field> {
+    // Cycles whose classes form a basis of homology.
+    classes: Vec<Vec<F>>,
+    // A basis of the boundaries.
+    boundaries: Vec<Vec<F>>,
+    // The size of the chain vectors.
+    chains: usize,
+}
+
+impl<F: Field> HomologyBasis<F> {
+    fn new<V: Ord + Hash + Clone>(
+        complex: &SimplicialComplex<V>,
+        union: &SimplicialComplex<V>,
+        dim: usize,
+    ) -> Self {
+        let simplices = union.simplices_of_dim(dim);
+        let chains = simplices.len();
+        let present = |s: &Vec<Vec<V>>| -> Vec<usize> {
+            (0..s.len()).filter(|&i| complex.contains(&s[i])).collect()
+        };
+
+        // Cycles supported on the complex.
+        let boundary = union.boundary_matrix::<F>(dim);
+        let columns = present(&simplices);
+        let restricted: Vec<Vec<F>> = columns.iter().map(|&c| boundary.column(c)).collect();
+        let cycles: Vec<Vec<F>> = Matrix::from_columns(boundary.rows(), &restricted)
+            .nullspace()
+            .into_iter()
+            .map(|z| {
+                let mut chain = vec![F::zero(); chains];
+                for (k, &c) in columns.iter().enumerate() {
+                    chain[c] = z[k];
+                }
+                chain
+            })
+            .collect();
+
+        // Boundaries of the complex's simplices one dimension up.
+        let cofaces = union.simplices_of_dim(dim + 1);
+        let coboundary = union.boundary_matrix::<F>(dim + 1);
+        let restricted: Vec<Vec<F>> = present(&cofaces)
+            .into_iter()
+            .map(|c| coboundary.column(c))
+            .collect();
+        let boundaries = Matrix::from_columns(chains, &restricted).column_basis();
+
+        let classes = extend_basis(chains, &boundaries, cycles);
+        Self {
+            classes,
+            boundaries,
+            chains,
+        }
+    }
+
+    // The coordinates of the class of a cycle in this basis.
+    fn coordinates(&self, cycle: &[F]) -> Vec<F> {
+        let mut columns = self.classes.clone();
+        columns.extend(self.boundaries.iter().cloned());
+        let solution = Matrix::from_columns(self.chains, &columns)
+            .solve(cycle)
+            .expect("cycle must be supported on the complex");
+        solution[..self.classes.len()].to_vec()
+    }
+
+    // The matrix of the map induced on homology by the inclusion of this
+    // complex into the complex of `target`.
+    fn inclusion_into(&self, target: &Self) -> Matrix<F> {
+        let columns: Vec<Vec<F>> = self.classes.iter().map(|c| target.coordinates(c)).collect();
+        Matrix::from_columns(target.classes.len(), &columns)
+    }
+}
+
+impl<F: Field> ZigzagModule<F> {
+    fn homology<V: Ord + Hash + Clone>(
+        filtration: &ZigzagFiltration<V>,
+        union: &SimplicialComplex<V>,
+        dim: usize,
+    ) -> Self {
+        let bases: Vec<HomologyBasis<F>> = filtration
+            .complexes
+            .iter()
+            .map(|k| HomologyBasis::new(k, union, dim))
+            .collect();
+        let maps = filtration
+            .directions
+            .iter()
+            .enumerate()
+            .map(|(i, direction)| match direction {
+                Direction::Forward => bases[i].inclusion_into(&bases[i + 1]),
+                Direction::Backward => bases[i + 1].inclusion_into(&bases[i]),
+            })
+            .collect();
+        Self {
+            dims: bases.iter().map(|b| b.classes.len()).collect(),
+            directions: filtration.directions.clone(),
+            maps,
+        }
+    }
+
+    // The number of bars containing every index in `start..=end`.
+    fn rank(&self, start: usize, end: usize) -> usize {
+        let mut offsets = vec![0];
+        for k in start..=end {
+            offsets.push(offsets.last().unwrap() + self.dims[k]);
+        }
+        let total = *offsets.last().unwrap();
+        let offset = |k: usize| offsets[k - start];
+
+        // Compatible families form the limit, and the relations they are
+        // quotiented by form the colimit.
+        let mut constraints = Vec::new();
+        let mut relations = Vec::new();
+        for k in start..end {
+            let map = &self.maps[k];
+            let (source, target) = match self.directions[k] {
+                Direction::Forward => (k, k + 1),
+                Direction::Backward => (k + 1, k),
+            };
+            for row in 0..map.rows() {
+                let mut constraint = vec![F::zero(); total];
+                for col in 0..map.cols() {
+                    constraint[offset(source) + col] = map.get(row, col);
+                }
+                constraint[offset(target) + row] = constraint[offset(target) + row] - F::one();
+                constraints.push(constraint);
+            }
+            for col in 0..map.cols() {
+                let mut relation = vec![F::zero(); total];
+                relation[offset(source) + col] = F::one();
+                for row in 0..map.rows() {
+                    relation[offset(target) + row] = -map.get(row, col);
+                }
+                relations.push(relation);
+            }
+        }
+        let limit = if constraints.is_empty() {
+            Matrix::<F>::identity(total).columns()
+        } else {
+            Matrix::from_rows(constraints).nullspace()
+        };
+        // The map from the limit to the colimit factors through the first
+        // space, so a compatible family is sent to the class of its first
+        // component.
+        let images = limit.into_iter().map(|mut family| {
+            for x in family.iter_mut().skip(self.dims[start]) {
+                *x = F::zero();
+            }
+            family
+        });
+        let relation_rank = Matrix::from_columns(total, &relations).rank();
+        relations.extend(images);
+        Matrix::from_columns(total, &relations).rank() - relation_rank
+    }
+
+    fn intervals(&self) -> Vec<(usize, usize)> {
+        let n = self.dims.len();
+        let ranks: Vec<Vec<isize>> = (0..n)
+            .map(|start| {
+                (0..n)
+                    .map(|end| {
+                        if end < start {
+                            0
+                        } else {
+                            self.rank(start, end) as isize
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        // Out of range lookups count no bars.
+        let rank = |start: isize, end: usize| -> isize {
+            if start < 0 || end >= n {
+                0
+            } else {
+                ranks[start as usize][end]
+            }
+        };
+        let mut intervals = Vec::new();
+        for birth in 0..n {
+            for death in birth..n {
+                let b = birth as isize;
+                let multiplicity = rank(b, death) - rank(b - 1, death) - rank(b, death + 1)
+                    + rank(b - 1, death + 1);
+                for _ in 0..multiplicity {
+                    intervals.push((birth, death));
+                }
+            }
+        }
+        intervals
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linear_algebra::Z2;
+
+    fn bars(barcode: &[Interval], dim: usize) -> Vec<(usize, usize)> {
+        barcode
+            .iter()
+            .filter(|i| i.dim == dim)
+            .map(|i| (i.birth, i.death))
+            .collect()
+    }
+
+    #[test]
+    fn components_merge_and_split() {
+        let mut filtration =
+            ZigzagFiltration::new(SimplicialComplex::from_simplices(vec![vec![0]]));
+        filtration.add(vec![1]);
+        filtration.add(vec![0, 1]);
+        filtration.remove(&[0, 1]);
+        filtration.remove(&[0]);
+        let barcode = filtration.barcode::<Z2>();
+        assert_eq!(bars(&barcode, 0), vec![(0, 4), (1, 1), (3, 3)]);
+        assert!(bars(&barcode, 1).is_empty());
+    }
+
+    #[test]
+    fn cycle_filled_and_reopened() {
+        let circle = SimplicialComplex::from_simplices(vec![vec![0, 1], vec![1, 2], vec![0, 2]]);
+        let mut filtration = ZigzagFiltration::new(circle);
+        filtration.add(vec![0, 1, 2]);
+        filtration.remove(&[0, 1, 2]);
+        let barcode = filtration.barcode::<Z2>();
+        assert_eq!(bars(&barcode, 0), vec![(0, 2)]);
+        assert_eq!(bars(&barcode, 1), vec![(0, 0), (2, 2)]);
+    }
+
+    #[test]
+    fn sensor_drops_out_and_returns() {
+        // A sensor joins a line of two sensors, drops out, and comes back.
+        let line = SimplicialComplex::from_simplices(vec![vec![0, 1]]);
+        let mut filtration = ZigzagFiltration::new(line);
+        filtration.add(vec![1, 2]);
+        filtration.remove(&[2]);
+        filtration.add(vec![2]);
+        let barcode = filtration.barcode::<Z2>();
+        assert_eq!(bars(&barcode, 0), vec![(0, 3), (3, 3)]);
+        assert_eq!(filtration.directions().len(), 3);
+    }
+}
@@ -0,0 +1,383 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    hash::Hash,
+};
+
+use crate::{
+    alexandrov::{FinitePreOrderedSet, PreOrderedSet},
+    spaces::{cell_complex::CellComplex, simplicial_complex::SimplicialComplex},
+};
+
+/// The subsets of a finite ground set, ordered by inclusion.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubsetLattice<T: Ord + Hash + Clone> {
+    pub ground: BTreeSet<T>,
+}
+
+impl<T: Ord + Hash + Clone> SubsetLattice<T> {
+    pub fn new<I: IntoIterator<Item = T>>(ground: I) -> Self {
+        Self {
+            ground: ground.into_iter().collect(),
+        }
+    }
+}
+
+impl<T: Ord + Hash + Clone> PreOrderedSet for SubsetLattice<T> {
+    type Element = BTreeSet<T>;
+
+    fn compare(&self, element_a: Self::Element, element_b: Self::Element) -> bool {
+        element_a.is_subset(&element_b)
+    }
+
+    fn upper_set(&self, element: Self::Element) -> Vec<Self::Element> {
+        let missing: Vec<T> = self.ground.difference(&element).cloned().collect();
+        power_set(&missing)
+            .into_iter()
+            .map(|extra| element.iter().cloned().chain(extra).collect())
+            .collect()
+    }
+}
+
+impl<T: Ord + Hash + Clone> FinitePreOrderedSet for SubsetLattice<T> {
+    fn elements(&self) -> Vec<Self::Element> {
+        power_set(&self.ground.iter().cloned().collect::<Vec<_>>())
+            .into_iter()
+            .map(|s| s.into_iter().collect())
+            .collect()
+    }
+}
+
+fn power_set<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    (0..1usize << items.len())
+        .map(|mask| {
+            (0..items.len())
+                .filter(|i| mask & (1 << i) != 0)
+                .map(|i| items[i].clone())
+                .collect()
+        })
+        .collect()
+}
+
+/// Positive integers ordered by divisibility.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Divisibility {
+    elements: Vec<u64>,
+}
+
+impl Divisibility {
+    /// The integers `1..=n`.
+    pub fn up_to(n: u64) -> Self {
+        Self {
+            elements: (1..=n).collect(),
+        }
+    }
+
+    /// The divisors of `n`. [`DivisorLattice`] orders the same elements as a
+    /// lattice.
+    pub fn divisors_of(n: u64) -> Self {
+        assert!(n > 0, "Only positive integers have finitely many divisors");
+        Self {
+            elements: (1..=n).filter(|d| n.is_multiple_of(*d)).collect(),
+        }
+    }
+}
+
+impl PreOrderedSet for Divisibility {
+    type Element = u64;
+
+    fn compare(&self, element_a: u64, element_b: u64) -> bool {
+        element_b.is_multiple_of(element_a)
+    }
+
+    fn upper_set(&self, element: u64) -> Vec<u64> {
+        self.elements
+            .iter()
+            .filter(|e| e.is_multiple_of(element))
+            .cloned()
+            .collect()
+    }
+}
+
+impl FinitePreOrderedSet for Divisibility {
+    fn elements(&self) -> Vec<u64> {
+        self.elements.clone()
+    }
+}
+
+/// The divisors of a positive integer ordered by divisibility. Unlike `1..=n`,
+/// they always form a lattice: the greatest common divisor and the least
+/// common multiple of two divisors are divisors again.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DivisorLattice {
+    n: u64,
+    divisors: Divisibility,
+}
+
+impl DivisorLattice {
+    pub fn new(n: u64) -> Self {
+        Self {
+            n,
+            divisors: Divisibility::divisors_of(n),
+        }
+    }
+
+    /// The number whose divisors these are, which is the top element.
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+}
+
+impl PreOrderedSet for DivisorLattice {
+    type Element = u64;
+
+    fn compare(&self, element_a: u64, element_b: u64) -> bool {
+        self.divisors.compare(element_a, element_b)
+    }
+
+    fn upper_set(&self, element: u64) -> Vec<u64> {
+        self.divisors.upper_set(element)
+    }
+}
+
+impl FinitePreOrderedSet for DivisorLattice {
+    fn elements(&self) -> Vec<u64> {
+        self.divisors.elements()
+    }
+}
+
+/// Pairs ordered componentwise.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProductOrder<A: PreOrderedSet, B: PreOrderedSet> {
+    pub left: A,
+    pub right: B,
+}
+
+impl<A: PreOrderedSet, B: PreOrderedSet> ProductOrder<A, B> {
+    pub fn new(left: A, right: B) -> Self {
+        Self { left, right }
+    }
+}
+
+impl<A: PreOrderedSet, B: PreOrderedSet> PreOrderedSet for ProductOrder<A, B> {
+    type Element = (A::Element, B::Element);
+
+    fn compare(&self, element_a: Self::Element, element_b: Self::Element) -> bool {
+        self.left.compare(element_a.0, element_b.0) && self.right.compare(element_a.1, element_b.1)
+    }
+
+    fn upper_set(&self, element: Self::Element) -> Vec<Self::Element> {
+        let right = self.right.upper_set(element.1);
+        self.left
+            .upper_set(element.0)
+            .into_iter()
+            .flat_map(|a| right.iter().map(move |b| (a.clone(), b.clone())))
+            .collect()
+    }
+}
+
+impl<A: FinitePreOrderedSet, B: FinitePreOrderedSet> FinitePreOrderedSet for ProductOrder<A, B> {
+    fn elements(&self) -> Vec<Self::Element> {
+        let right = self.right.elements();
+        self.left
+            .elements()
+            .into_iter()
+            .flat_map(|a| right.iter().map(move |b| (a.clone(), b.clone())))
+            .collect()
+    }
+}
+
+/// Finitely many values in their natural order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TotalOrder<T: Ord + Hash + Clone> {
+    elements: Vec<T>,
+}
+
+impl<T: Ord + Hash + Clone> TotalOrder<T> {
+    pub fn new<I: IntoIterator<Item = T>>(elements: I) -> Self {
+        let mut elements: Vec<T> = elements.into_iter().collect();
+        elements.sort();
+        elements.dedup();
+        Self { elements }
+    }
+}
+
+impl<T: Ord + Hash + Clone> PreOrderedSet for TotalOrder<T> {
+    type Element = T;
+
+    fn compare(&self, element_a: T, element_b: T) -> bool {
+        element_a <= element_b
+    }
+
+    fn upper_set(&self, element: T) -> Vec<T> {
+        let start = self.elements.partition_point(|e| *e < element);
+        self.elements[start..].to_vec()
+    }
+}
+
+impl<T: Ord + Hash + Clone> FinitePreOrderedSet for TotalOrder<T> {
+    fn elements(&self) -> Vec<T> {
+        self.elements.clone()
+    }
+}
+
+/// The cells of a complex ordered by the face relation, with `a ≤ b` when `a`
+/// is a face of `b`. Its Alexandrov space is the finite model of the complex.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FacePoset<E: Clone + Eq + Hash> {
+    elements: Vec<E>,
+    upper_sets: HashMap<E, Vec<E>>,
+}
+
+impl<V: Ord + Hash + Clone> FacePoset<Vec<V>> {
+    /// The face poset of a simplicial complex, whose elements are simplices.
+    pub fn from_simplicial_complex(complex: &SimplicialComplex<V>) -> Self {
+        let elements: Vec<Vec<V>> = complex.simplices().cloned().collect();
+        let upper_sets = elements
+            .iter()
+            .map(|s| {
+                let mut upper = vec![s.clone()];
+                upper.extend(complex.cofaces(s));
+                (s.clone(), upper)
+            })
+            .collect();
+        Self {
+            elements,
+            upper_sets,
+        }
+    }
+}
+
+impl FacePoset<usize> {
+    /// The face poset of a cell complex, whose elements are indices into
+    /// `cells`. A cell is a face of another when it has lower dimension and
+    /// all of its points lie in the other cell.
+    pub fn from_cell_complex<T: Eq + Hash + Clone>(complex: &CellComplex<T>) -> Self {
+        let elements: Vec<usize> = (0..complex.cells.len()).collect();
+        let upper_sets = complex
+            .cofaces()
+            .into_iter()
+            .enumerate()
+            .map(|(i, cofaces)| {
+                let mut upper = vec![i];
+                upper.extend(cofaces);
+                (i, upper)
+            })
+            .collect();
+        Self {
+            elements,
+            upper_sets,
+        }
+    }
+}
+
+impl<E: Clone + Eq + Hash> PreOrderedSet for FacePoset<E> {
+    type Element = E;
+
+    fn compare(&self, element_a: E, element_b: E) -> bool {
+        self.upper_sets
+            .get(&element_a)
+            .is_some_and(|u| u.contains(&element_b))
+    }
+
+    fn upper_set(&self, element: E) -> Vec<E> {
+        self.upper_sets.get(&element).cloned().unwrap_or_default()
+    }
+}
+
+impl<E: Clone + Eq + Hash> FinitePreOrderedSet for FacePoset<E> {
+    fn elements(&self) -> Vec<E> {
+        self.elements.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, rc::Rc};
+
+    use super::*;
+    use crate::{
+        alexandrov::AlexandrovSpace,
+        spaces::{
+            cell_complex::{Point, Skeleton},
+            constructions::FiniteCell,
+        },
+        topology::FiniteTopologicalSpace,
+    };
+
+    fn set<T: Ord + Clone>(items: &[T]) -> BTreeSet<T> {
+        items.iter().cloned().collect()
+    }
+
+    #[test]
+    fn subsets() {
+        let lattice = SubsetLattice::new(vec!['a', 'b', 'c']);
+        assert_eq!(lattice.elements().len(), 8);
+        assert!(lattice.compare(set(&['a']), set(&['a', 'c'])));
+        assert!(!lattice.compare(set(&['b']), set(&['a', 'c'])));
+        let upper: HashSet<BTreeSet<char>> =
+            lattice.upper_set(set(&['a', 'b'])).into_iter().collect();
+        assert_eq!(
+            upper,
+            vec![set(&['a', 'b']), set(&['a', 'b', 'c'])]
+                .into_iter()
+                .collect()
+        );
+    }
+
+    #[test]
+    fn divisors() {
+        let divisors = Divisibility::divisors_of(12);
+        assert_eq!(divisors.elements(), vec![1, 2, 3, 4, 6, 12]);
+        assert_eq!(divisors.upper_set(2), vec![2, 4, 6, 12]);
+        assert!(divisors.compare(3, 12));
+        assert!(!divisors.compare(4, 6));
+        assert_eq!(Divisibility::up_to(10).upper_set(3), vec![3, 6, 9]);
+        assert_eq!(DivisorLattice::new(12).elements(), divisors.elements());
+        assert_eq!(DivisorLattice::new(12).n(), 12);
+    }
+
+    #[test]
+    fn products_and_totals() {
+        let order = ProductOrder::new(TotalOrder::new(vec![2, 0, 1, 1]), Divisibility::up_to(4));
+        assert_eq!(order.elements().len(), 12);
+        assert!(order.compare((0, 2), (1, 4)));
+        assert!(!order.compare((0, 2), (1, 3)));
+        assert!(!order.compare((2, 1), (1, 1)));
+        assert_eq!(
+            order.upper_set((1, 2)),
+            vec![(1, 2), (1, 4), (2, 2), (2, 4)]
+        );
+    }
+
+    #[test]
+    fn face_poset_of_triangle() {
+        let triangle = SimplicialComplex::from_simplices(vec![vec![0, 1, 2]]);
+        let poset = FacePoset::from_simplicial_complex(&triangle);
+        assert_eq!(poset.elements().len(), 7);
+        assert!(poset.compare(vec![0], vec![0, 2]));
+        assert!(!poset.compare(vec![0, 1], vec![0, 2]));
+        assert_eq!(poset.upper_set(vec![1, 2]).len(), 2);
+        // The finite model of a contractible complex is connected.
+        assert!(AlexandrovSpace::new(poset).is_connected());
+    }
+
+    #[test]
+    fn face_poset_of_cell_complex() {
+        let mut skeleton = Skeleton::new();
+        for (points, dim) in [(vec![0], 0), (vec![1], 0), (vec![0, 1], 1)] {
+            let neighborhoods = points
+                .into_iter()
+                .map(|p| (Point(p), HashSet::from([Point(p)])))
+                .collect();
+            skeleton.include_cell(Rc::new(FiniteCell::new(dim, neighborhoods)));
+        }
+        let interval = CellComplex::new(skeleton, 1);
+        let poset = FacePoset::from_cell_complex(&interval);
+        assert_eq!(poset.upper_set(0), vec![0, 2]);
+        assert!(poset.compare(1, 2));
+        assert!(!poset.compare(2, 0));
+
+        let cone = FacePoset::from_cell_complex(&interval.cone());
+        assert_eq!(cone.elements().len(), 7);
+    }
+}
@@ -0,0 +1,190 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use crate::{spaces::quotient::Quotient, topology::FiniteTopologicalSpace};
+
+/// Which separation axioms a finite space satisfies. Each field is `None`
+/// when the axiom holds, and otherwise holds a witness that cannot be
+/// separated.
+///
+/// Regularity and normality are taken without the T1 axiom, so T3 is T0 with
+/// regularity and T4 is T1 with normality.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SeparationReport<P: Eq + Hash> {
+    /// Two distinct points with exactly the same open neighborhoods.
+    pub t0: Option<(P, P)>,
+    /// Two distinct points such that every neighborhood of the first contains
+    /// the second.
+    pub t1: Option<(P, P)>,
+    /// Two distinct points whose neighborhoods always meet.
+    pub t2: Option<(P, P)>,
+    /// A point and a closed set without it whose neighborhoods always meet.
+    pub regular: Option<(P, HashSet<P>)>,
+    /// Two disjoint closed sets whose neighborhoods always meet.
+    pub normal: Option<(HashSet<P>, HashSet<P>)>,
+}
+
+impl<P: Eq + Hash> SeparationReport<P> {
+    pub fn is_t0(&self) -> bool {
+        self.t0.is_none()
+    }
+
+    pub fn is_t1(&self) -> bool {
+        self.t1.is_none()
+    }
+
+    pub fn is_t2(&self) -> bool {
+        self.t2.is_none()
+    }
+
+    pub fn is_regular(&self) -> bool {
+        self.regular.is_none()
+    }
+
+    pub fn is_normal(&self) -> bool {
+        self.normal.is_none()
+    }
+
+    /// A finite T1 space is discrete, so every set is open and the topology
+    /// carries no information.
+    pub fn is_discrete(&self) -> bool {
+        self.is_t1()
+    }
+}
+
+/// Checks the separation axioms of a finite space.
+///
+/// Everything reduces to smallest neighborhoods `U_x`. When a closed set
+/// cannot be separated from a point or from another closed set, neither can
+/// the closure of one of its points, so only closures of points are tried.
+pub fn classify<X: FiniteTopologicalSpace>(space: &X) -> SeparationReport<X::Point> {
+    let points: Vec<X::Point> = space.points().into_iter().collect();
+    let neighborhoods: HashMap<X::Point, HashSet<X::Point>> = points
+        .iter()
+        .map(|p| (p.clone(), space.minimal_neighborhood(p.clone())))
+        .collect();
+    let closures: HashMap<X::Point, HashSet<X::Point>> = points
+        .iter()
+        .map(|p| (p.clone(), space.closure(&HashSet::from([p.clone()]))))
+        .collect();
+    let meet = |a: &X::Point, b: &X::Point| !neighborhoods[a].is_disjoint(&neighborhoods[b]);
+    let pairs = || {
+        points
+            .iter()
+            .flat_map(|a| points.iter().map(move |b| (a, b)))
+            .filter(|(a, b)| a != b)
+    };
+
+    let t0 = pairs()
+        .find(|(a, b)| neighborhoods[a].contains(*b) && neighborhoods[b].contains(*a))
+        .map(|(a, b)| (a.clone(), b.clone()));
+    let t1 = pairs()
+        .find(|(a, b)| neighborhoods[a].contains(*b))
+        .map(|(a, b)| (a.clone(), b.clone()));
+    let t2 = pairs()
+        .find(|(a, b)| meet(a, b))
+        .map(|(a, b)| (a.clone(), b.clone()));
+    let regular = pairs()
+        .find(|(a, b)| !closures[*b].contains(*a) && meet(a, b))
+        .map(|(a, b)| (a.clone(), closures[b].clone()));
+    let normal = pairs()
+        .find(|(a, b)| closures[*a].is_disjoint(&closures[*b]) && meet(a, b))
+        .map(|(a, b)| (closures[a].clone(), closures[b].clone()));
+    SeparationReport {
+        t0,
+        t1,
+        t2,
+        regular,
+        normal,
+    }
+}
+
+/// The Kolmogorov quotient, identifying points with the same open
+/// neighborhoods. It is always T0, and a set is open in the space exactly when
+/// it is the preimage of an open set of the quotient.
+pub fn kolmogorov_quotient<X: FiniteTopologicalSpace>(space: &X) -> Quotient<'_, X> {
+    let neighborhoods: HashMap<X::Point, HashSet<X::Point>> = space
+        .points()
+        .into_iter()
+        .map(|p| (p.clone(), space.minimal_neighborhood(p)))
+        .collect();
+    Quotient::from_relation(space, |a, b| neighborhoods[a] == neighborhoods[b])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        spaces::{finite_topology::FiniteTopology, undirected_graph::UndirectedGraph},
+        test_support::{set, sierpinski},
+    };
+
+    #[test]
+    fn sierpinski_is_only_t0() {
+        let space = sierpinski();
+        let report = classify(&space);
+        assert!(report.is_t0());
+        assert_eq!(report.t1, Some(('b', 'a')));
+        assert!(!report.is_t2());
+        assert_eq!(report.regular, Some(('a', set(&['b']))));
+        assert!(report.is_normal());
+        assert!(!report.is_discrete());
+    }
+
+    #[test]
+    fn chain_is_not_regular() {
+        // Open sets {a}, {a, b} and everything.
+        let space = FiniteTopology::from_subbasis(
+            set(&['a', 'b', 'c']),
+            vec![set(&['a']), set(&['a', 'b'])],
+        );
+        let report = classify(&space);
+        assert!(report.is_t0());
+        assert!(report.is_normal());
+        let (point, closed) = report.regular.unwrap();
+        assert!(!closed.contains(&point));
+        assert!(space.is_closed(&closed));
+    }
+
+    #[test]
+    fn two_closed_points_under_a_common_open_point_are_not_normal() {
+        let space = FiniteTopology::from_subbasis(
+            set(&['a', 'b', 'c']),
+            vec![set(&['a']), set(&['a', 'b']), set(&['a', 'c'])],
+        );
+        let report = classify(&space);
+        let (first, second) = report.normal.unwrap();
+        assert!(first.is_disjoint(&second));
+        let mut closed = vec![first, second];
+        closed.sort_by_key(|c| c.contains(&'c'));
+        assert_eq!(closed, vec![set(&['b']), set(&['c'])]);
+    }
+
+    #[test]
+    fn graphs_are_discrete() {
+        let graph = UndirectedGraph::new(
+            vec![0, 1, 2].into_iter().collect(),
+            vec![(0, 1), (1, 2)].into_iter().collect(),
+        );
+        let report = classify(&graph);
+        assert!(report.is_discrete());
+        assert!(report.is_t2() && report.is_regular() && report.is_normal());
+    }
+
+    #[test]
+    fn kolmogorov_quotient_is_t0() {
+        // 'b' and 'c' have the same neighborhoods.
+        let space = FiniteTopology::from_subbasis(set(&['a', 'b', 'c']), vec![set(&['a'])]);
+        let report = classify(&space);
+        let (x, y) = report.t0.unwrap();
+        assert_eq!(set(&[x, y]), set(&['b', 'c']));
+
+        let quotient = kolmogorov_quotient(&space);
+        assert_eq!(quotient.classes().len(), 2);
+        assert_eq!(quotient.class_of(&'b'), quotient.class_of(&'c'));
+        assert!(classify(&quotient).is_t0());
+        assert!(!classify(&quotient).is_t1());
+    }
+}
@@ -0,0 +1,836 @@
+//! Morphisms of sheaves of vector spaces on finite preorders, with their
+//! kernels, images and cokernels, and the direct and inverse images along
+//! monotone maps.
+//!
+//! Everything here works with [`AlexandrovSheaf`] rather than the
+//! [`Sheaf`](crate::topology::Sheaf) and
+//! [`PreSheaf`](crate::topology::PreSheaf) traits, which describe sections over
+//! arbitrary open sets. A sheaf on a finite topological space is a sheaf on its
+//! [`SpecializationOrder`], which [`AlexandrovSheaf::on_space`] builds, and a
+//! continuous map gives a monotone map between these orders through
+//! [`MonotoneMap::from_continuous`]. Methods ending in `_along` take the
+//! continuous map and the other space and do this conversion themselves.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Debug, Formatter},
+};
+
+use crate::{
+    alexandrov::{AlexandrovSheaf, FinitePreOrderedSet, MonotoneMap, SpecializationOrder},
+    linear_algebra::{extend_basis, Field, Matrix},
+    topology::{ContinuousMap, FiniteTopologicalSpace},
+};
+
+/// A morphism between two sheaves on the same finite preorder, given by a
+/// linear map between the stalks at every element.
+///
+/// The maps only form a morphism when they commute with the restrictions,
+/// which [`SheafMorphism::is_natural`] checks. The sheaves themselves are
+/// passed to the methods that need them, so kernels and cokernels can return
+/// both a new sheaf and the morphism relating it to the old ones.
+pub struct SheafMorphism<O: FinitePreOrderedSet, F: Field> {
+    components: HashMap<O::Element, Matrix<F>>,
+}
+
+// Implemented by hand so that only the elements, not the order, need to be
+// comparable and printable.
+impl<O: FinitePreOrderedSet, F: Field> Clone for SheafMorphism<O, F> {
+    fn clone(&self) -> Self {
+        Self::new(self.components.clone())
+    }
+}
+
+impl<O: FinitePreOrderedSet, F: Field> PartialEq for SheafMorphism<O, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.components == other.components
+    }
+}
+
+impl<O: FinitePreOrderedSet, F: Field> Eq for SheafMorphism<O, F> {}
+
+impl<O: FinitePreOrderedSet, F: Field> Debug for SheafMorphism<O, F>
+where
+    O::Element: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(&self.components).finish()
+    }
+}
+
+impl<O: FinitePreOrderedSet, F: Field> SheafMorphism<O, F> {
+    pub fn new(components: HashMap<O::Element, Matrix<F>>) -> Self {
+        Self { components }
+    }
+
+    pub fn identity(sheaf: &AlexandrovSheaf<O, F>) -> Self {
+        Self::new(
+            sheaf
+                .order
+                .elements()
+                .into_iter()
+                .map(|e| {
+                    let n = sheaf.stalk(&e);
+                    (e, Matrix::identity(n))
+                })
+                .collect(),
+        )
+    }
+
+    pub fn zero(source: &AlexandrovSheaf<O, F>, target: &AlexandrovSheaf<O, F>) -> Self {
+        Self::new(
+            source
+                .order
+                .elements()
+                .into_iter()
+                .map(|e| {
+                    let map = Matrix::zeros(target.stalk(&e), source.stalk(&e));
+                    (e, map)
+                })
+                .collect(),
+        )
+    }
+
+    /// The map from the stalk of the source at `element` to the stalk of the
+    /// target.
+    pub fn component(&self, element: &O::Element) -> Option<&Matrix<F>> {
+        self.components.get(element)
+    }
+
+    fn expect_component(&self, element: &O::Element) -> &Matrix<F> {
+        self.component(element)
+            .expect("A morphism needs a component at every element")
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.components.values().all(|map| map.is_zero())
+    }
+
+    /// Checks that every component maps the stalk of `source` to the stalk of
+    /// `target`, and that restricting then mapping agrees with mapping then
+    /// restricting.
+    pub fn is_natural(
+        &self,
+        source: &AlexandrovSheaf<O, F>,
+        target: &AlexandrovSheaf<O, F>,
+    ) -> bool {
+        let elements = source.order.elements();
+        for x in &elements {
+            match self.component(x) {
+                Some(map) if map.rows() == target.stalk(x) && map.cols() == source.stalk(x) => {}
+                _ => return false,
+            }
+        }
+        for x in &elements {
+            for y in source.order.upper_set(x.clone()) {
+                match (source.restriction(x, &y), target.restriction(x, &y)) {
+                    (Some(before), Some(after))
+                        if self.expect_component(&y) * &before
+                            == &after * self.expect_component(x) => {}
+                    _ => return false,
+                }
+            }
+        }
+        true
+    }
+
+    /// The composite that applies `self` and then `other`. Both need
+    /// components at the same elements, and the target of each component of
+    /// `self` has to be the source of the one of `other`.
+    pub fn then(&self, other: &Self) -> Self {
+        assert!(
+            self.components.len() == other.components.len()
+                && self
+                    .components
+                    .keys()
+                    .all(|e| other.components.contains_key(e)),
+            "Composable morphisms must have components at the same elements",
+        );
+        Self::new(
+            self.components
+                .iter()
+                .map(|(e, first)| {
+                    let second = other.expect_component(e);
+                    assert!(
+                        second.cols() == first.rows(),
+                        "The second morphism must start where the first one ends",
+                    );
+                    (e.clone(), second * first)
+                })
+                .collect(),
+        )
+    }
+}
+
+impl<O: FinitePreOrderedSet + Clone, F: Field> SheafMorphism<O, F> {
+    /// The kernel sheaf, whose stalks are the kernels of the components, with
+    /// its inclusion into `source`.
+    pub fn kernel(
+        &self,
+        source: &AlexandrovSheaf<O, F>,
+        target: &AlexandrovSheaf<O, F>,
+    ) -> (AlexandrovSheaf<O, F>, Self) {
+        assert!(
+            self.is_natural(source, target),
+            "The maps must form a morphism from `source` to `target`",
+        );
+        let bases = source
+            .order
+            .elements()
+            .into_iter()
+            .map(|e| {
+                let basis = self.expect_component(&e).nullspace();
+                let basis = Matrix::from_columns(source.stalk(&e), &basis);
+                (e, basis)
+            })
+            .collect();
+        subsheaf(source, bases)
+    }
+
+    /// The image sheaf, whose stalks are the images of the components, with
+    /// its inclusion into `target`.
+    pub fn image(
+        &self,
+        source: &AlexandrovSheaf<O, F>,
+        target: &AlexandrovSheaf<O, F>,
+    ) -> (AlexandrovSheaf<O, F>, Self) {
+        assert!(
+            self.is_natural(source, target),
+            "The maps must form a morphism from `source` to `target`",
+        );
+        let bases = source
+            .order
+            .elements()
+            .into_iter()
+            .map(|e| {
+                let basis = self.expect_component(&e).column_basis();
+                let basis = Matrix::from_columns(target.stalk(&e), &basis);
+                (e, basis)
+            })
+            .collect();
+        subsheaf(target, bases)
+    }
+
+    /// The cokernel sheaf, whose stalks are the quotients of the stalks of
+    /// `target` by the images of the components, with the projection onto
+    /// it.
+    pub fn cokernel(
+        &self,
+        source: &AlexandrovSheaf<O, F>,
+        target: &AlexandrovSheaf<O, F>,
+    ) -> (AlexandrovSheaf<O, F>, Self) {
+        assert!(
+            self.is_natural(source, target),
+            "The maps must form a morphism from `source` to `target`",
+        );
+        let elements = target.order.elements();
+        let mut complements = HashMap::new();
+        let mut projections = HashMap::new();
+        for e in &elements {
+            let n = target.stalk(e);
+            let image = self.expect_component(e).column_basis();
+            let standard = Matrix::<F>::identity(n).columns();
+            let complement = extend_basis(n, &image, standard);
+            // In the basis of the image followed by the complement, the
+            // quotient keeps the complement coordinates.
+            let basis = Matrix::from_columns(n, &[image.clone(), complement.clone()].concat());
+            let coordinates = solve_columns(&basis, &Matrix::identity(n));
+            let mut projection = Matrix::zeros(complement.len(), n);
+            for i in 0..complement.len() {
+                for j in 0..n {
+                    projection.set(i, j, coordinates.get(image.len() + i, j));
+                }
+            }
+            complements.insert(e.clone(), Matrix::from_columns(n, &complement));
+            projections.insert(e.clone(), projection);
+        }
+        let stalks = elements
+            .iter()
+            .map(|e| (e.clone(), complements[e].cols()))
+            .collect();
+        let mut quotient = AlexandrovSheaf::new(target.order.clone(), stalks);
+        for x in &elements {
+            for y in target.order.upper_set(x.clone()) {
+                if y == *x {
+                    continue;
+                }
+                let restriction = target
+                    .restriction(x, &y)
+                    .expect("every relation needs a restriction");
+                let map = &projections[&y] * &(&restriction * &complements[x]);
+                quotient.set_restriction(x.clone(), y, map);
+            }
+        }
+        (quotient, Self::new(projections))
+    }
+}
+
+// Finds the matrix `m` with `basis * m = target`, where the columns of
+// `basis` are independent and span the columns of `target`.
+fn solve_columns<F: Field>(basis: &Matrix<F>, target: &Matrix<F>) -> Matrix<F> {
+    let columns: Vec<Vec<F>> = target
+        .columns()
+        .iter()
+        .map(|c| basis.solve(c).expect("The column must lie in the span"))
+        .collect();
+    Matrix::from_columns(basis.cols(), &columns)
+}
+
+// The subsheaf spanned by the columns of `bases` at each element, which must be
+// preserved by the restrictions, and its inclusion.
+fn subsheaf<O: FinitePreOrderedSet + Clone, F: Field>(
+    sheaf: &AlexandrovSheaf<O, F>,
+    bases: HashMap<O::Element, Matrix<F>>,
+) -> (AlexandrovSheaf<O, F>, SheafMorphism<O, F>) {
+    let stalks = bases.iter().map(|(e, b)| (e.clone(), b.cols())).collect();
+    let mut sub = AlexandrovSheaf::new(sheaf.order.clone(), stalks);
+    for x in sheaf.order.elements() {
+        for y in sheaf.order.upper_set(x.clone()) {
+            if y == x {
+                continue;
+            }
+            let restriction = sheaf
+                .restriction(&x, &y)
+                .expect("every relation needs a restriction");
+            let map = solve_columns(&bases[&y], &(&restriction * &bases[&x]));
+            sub.set_restriction(x.clone(), y, map);
+        }
+    }
+    (sub, SheafMorphism::new(bases))
+}
+
+// The sections over `open` as the columns of a matrix, in the layout of
+// `AlexandrovSheaf::sections_over`.
+fn section_basis<O: FinitePreOrderedSet, F: Field>(
+    sheaf: &AlexandrovSheaf<O, F>,
+    open: &HashSet<O::Element>,
+) -> Matrix<F> {
+    let rows = open.iter().map(|e| sheaf.stalk(e)).sum();
+    Matrix::from_columns(rows, &sheaf.sections_over(open))
+}
+
+// The rows of `sections`, laid out over `open`, that hold the values at the
+// elements of `smaller`.
+fn restrict_sections<O: FinitePreOrderedSet, F: Field>(
+    sheaf: &AlexandrovSheaf<O, F>,
+    sections: &Matrix<F>,
+    open: &HashSet<O::Element>,
+    smaller: &HashSet<O::Element>,
+) -> Matrix<F> {
+    let mut rows = Vec::new();
+    let mut offset = 0;
+    for e in sheaf.order.elements() {
+        if !open.contains(&e) {
+            continue;
+        }
+        let n = sheaf.stalk(&e);
+        if smaller.contains(&e) {
+            rows.extend(offset..offset + n);
+        }
+        offset += n;
+    }
+    let mut restricted = Matrix::zeros(rows.len(), sections.cols());
+    for (i, &row) in rows.iter().enumerate() {
+        for j in 0..sections.cols() {
+            restricted.set(i, j, sections.get(row, j));
+        }
+    }
+    restricted
+}
+
+impl<'a, X: FiniteTopologicalSpace, F: Field> AlexandrovSheaf<SpecializationOrder<'a, X>, F> {
+    /// A sheaf on a finite space with the given stalk dimensions and no
+    /// restrictions yet. Restrictions go from a point to the points of its
+    /// smallest neighborhood, so morphisms between two such sheaves are
+    /// morphisms of sheaves on `space`.
+    pub fn on_space(space: &'a X, stalks: HashMap<X::Point, usize>) -> Self {
+        Self::new(SpecializationOrder::new(space), stalks)
+    }
+}
+
+// The elements that `map` sends into the smallest neighborhood of `target`.
+fn preimage<P: FinitePreOrderedSet, Q: FinitePreOrderedSet>(
+    map: &MonotoneMap<P, Q>,
+    domain: &P,
+    codomain: &Q,
+    target: &Q::Element,
+) -> HashSet<P::Element> {
+    domain
+        .elements()
+        .into_iter()
+        .filter(|p| codomain.compare(target.clone(), map.apply(p.clone())))
+        .collect()
+}
+
+impl<P: FinitePreOrderedSet, F: Field> AlexandrovSheaf<P, F> {
+    /// The pushforward along a monotone map into `codomain`. Its stalk at `q`
+    /// is the space of sections over the preimage of the smallest neighborhood
+    /// of `q`, and its restrictions restrict those sections.
+    pub fn pushforward<Q: FinitePreOrderedSet>(
+        &self,
+        map: &MonotoneMap<P, Q>,
+        codomain: Q,
+    ) -> AlexandrovSheaf<Q, F> {
+        assert!(
+            map.is_monotone(&self.order, &codomain),
+            "Sheaves can only be pushed forward along monotone maps",
+        );
+        let elements = codomain.elements();
+        let preimages: HashMap<Q::Element, HashSet<P::Element>> = elements
+            .iter()
+            .map(|q| (q.clone(), preimage(map, &self.order, &codomain, q)))
+            .collect();
+        let bases: HashMap<Q::Element, Matrix<F>> = elements
+            .iter()
+            .map(|q| (q.clone(), section_basis(self, &preimages[q])))
+            .collect();
+        let stalks = elements
+            .iter()
+            .map(|q| (q.clone(), bases[q].cols()))
+            .collect();
+        let mut pushed = AlexandrovSheaf::new(codomain, stalks);
+        for q in &elements {
+            for r in pushed.order.upper_set(q.clone()) {
+                if r == *q {
+                    continue;
+                }
+                let restricted = restrict_sections(self, &bases[q], &preimages[q], &preimages[&r]);
+                let restriction = solve_columns(&bases[&r], &restricted);
+                pushed.set_restriction(q.clone(), r, restriction);
+            }
+        }
+        pushed
+    }
+
+    /// The pullback along a monotone map from `domain`, which has the stalk at
+    /// `f(r)` at every element `r`.
+    pub fn pullback<R: FinitePreOrderedSet>(
+        &self,
+        map: &MonotoneMap<R, P>,
+        domain: R,
+    ) -> AlexandrovSheaf<R, F> {
+        assert!(
+            map.is_monotone(&domain, &self.order),
+            "Sheaves can only be pulled back along monotone maps",
+        );
+        let stalks = domain
+            .elements()
+            .into_iter()
+            .map(|r| {
+                let n = self.stalk(&map.apply(r.clone()));
+                (r, n)
+            })
+            .collect();
+        let mut pulled = AlexandrovSheaf::new(domain, stalks);
+        for r in pulled.order.elements() {
+            for s in pulled.order.upper_set(r.clone()) {
+                if s == r {
+                    continue;
+                }
+                let restriction = self
+                    .restriction(&map.apply(r.clone()), &map.apply(s.clone()))
+                    .expect("every relation needs a restriction");
+                pulled.set_restriction(r.clone(), s, restriction);
+            }
+        }
+        pulled
+    }
+}
+
+impl<P: FinitePreOrderedSet + Clone, F: Field> AlexandrovSheaf<P, F> {
+    /// The unit of the adjunction between pullback and pushforward at this
+    /// sheaf `G` on the codomain of `map`, together with its target
+    /// `f_* f^* G`. It sends a value at `q` to its restrictions to every
+    /// `f(r)` above `q`.
+    pub fn adjunction_unit<R: FinitePreOrderedSet>(
+        &self,
+        map: &MonotoneMap<R, P>,
+        domain: R,
+    ) -> (AlexandrovSheaf<P, F>, SheafMorphism<P, F>) {
+        let pulled = self.pullback(map, domain);
+        let pushed = pulled.pushforward(map, self.order.clone());
+        let components = self
+            .order
+            .elements()
+            .into_iter()
+            .map(|q| {
+                let open = preimage(map, &pulled.order, &self.order, &q);
+                let blocks: Vec<Matrix<F>> = pulled
+                    .order
+                    .elements()
+                    .into_iter()
+                    .filter(|r| open.contains(r))
+                    .map(|r| {
+                        self.restriction(&q, &map.apply(r))
+                            .expect("every relation needs a restriction")
+                    })
+                    .collect();
+                let rows = blocks.iter().map(|b| b.rows()).sum();
+                let mut values = Matrix::zeros(rows, self.stalk(&q));
+                let mut offset = 0;
+                for block in blocks {
+                    for i in 0..block.rows() {
+                        for j in 0..block.cols() {
+                            values.set(offset + i, j, block.get(i, j));
+                        }
+                    }
+                    offset += block.rows();
+                }
+                let component = solve_columns(&section_basis(&pulled, &open), &values);
+                (q, component)
+            })
+            .collect();
+        (pushed, SheafMorphism::new(components))
+    }
+
+    /// The counit of the adjunction between pullback and pushforward at this
+    /// sheaf `F` on the domain of `map`, together with its source
+    /// `f^* f_* F`. It sends a section over the preimage of the neighborhood
+    /// of `f(p)` to its value at `p`.
+    pub fn adjunction_counit<Q: FinitePreOrderedSet>(
+        &self,
+        map: &MonotoneMap<P, Q>,
+        codomain: Q,
+    ) -> (AlexandrovSheaf<P, F>, SheafMorphism<P, F>) {
+        let pushed = self.pushforward(map, codomain);
+        let pulled = pushed.pullback(map, self.order.clone());
+        let components = self
+            .order
+            .elements()
+            .into_iter()
+            .map(|p| {
+                let open = preimage(map, &self.order, &pushed.order, &map.apply(p.clone()));
+                let basis = section_basis(self, &open);
+                let component = restrict_sections(self, &basis, &open, &HashSet::from([p.clone()]));
+                (p, component)
+            })
+            .collect();
+        (pulled, SheafMorphism::new(components))
+    }
+}
+
+impl<'a, X: FiniteTopologicalSpace + 'static, F: Field>
+    AlexandrovSheaf<SpecializationOrder<'a, X>, F>
+{
+    /// The pushforward along a continuous map into the finite space
+    /// `codomain`.
+    pub fn pushforward_along<'b, Y: FiniteTopologicalSpace + 'static>(
+        &self,
+        map: &ContinuousMap<X, Y>,
+        codomain: &'b Y,
+    ) -> AlexandrovSheaf<SpecializationOrder<'b, Y>, F> {
+        self.pushforward(
+            &MonotoneMap::from_continuous(map),
+            SpecializationOrder::new(codomain),
+        )
+    }
+
+    /// The pullback along a continuous map from the finite space `domain`.
+    pub fn pullback_along<'b, W: FiniteTopologicalSpace + 'static>(
+        &self,
+        map: &ContinuousMap<W, X>,
+        domain: &'b W,
+    ) -> AlexandrovSheaf<SpecializationOrder<'b, W>, F> {
+        self.pullback(
+            &MonotoneMap::from_continuous(map),
+            SpecializationOrder::new(domain),
+        )
+    }
+
+    /// [`AlexandrovSheaf::adjunction_unit`] for a continuous map from the
+    /// finite space `domain`.
+    pub fn adjunction_unit_along<W: FiniteTopologicalSpace + 'static>(
+        &self,
+        map: &ContinuousMap<W, X>,
+        domain: &W,
+    ) -> (Self, SheafMorphism<SpecializationOrder<'a, X>, F>) {
+        self.adjunction_unit(
+            &MonotoneMap::from_continuous(map),
+            SpecializationOrder::new(domain),
+        )
+    }
+
+    /// [`AlexandrovSheaf::adjunction_counit`] for a continuous map into the
+    /// finite space `codomain`.
+    pub fn adjunction_counit_along<Y: FiniteTopologicalSpace + 'static>(
+        &self,
+        map: &ContinuousMap<X, Y>,
+        codomain: &Y,
+    ) -> (Self, SheafMorphism<SpecializationOrder<'a, X>, F>) {
+        self.adjunction_counit(
+            &MonotoneMap::from_continuous(map),
+            SpecializationOrder::new(codomain),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::{
+        alexandrov::PreOrderedSet, linear_algebra::Rational, posets::TotalOrder,
+        spaces::finite_topology::FiniteTopology, test_support::sierpinski,
+    };
+
+    fn q(n: i64) -> Rational {
+        Rational::from(n)
+    }
+
+    fn constant(order: TotalOrder<u32>, k: usize) -> AlexandrovSheaf<TotalOrder<u32>, Rational> {
+        let stalks = order.elements().into_iter().map(|e| (e, k)).collect();
+        let mut sheaf = AlexandrovSheaf::new(order, stalks);
+        for x in sheaf.order.elements() {
+            for y in sheaf.order.upper_set(x) {
+                sheaf.set_restriction(x, y, Matrix::identity(k));
+            }
+        }
+        sheaf
+    }
+
+    fn everywhere(
+        order: &TotalOrder<u32>,
+        map: Matrix<Rational>,
+    ) -> SheafMorphism<TotalOrder<u32>, Rational> {
+        SheafMorphism::new(
+            order
+                .elements()
+                .into_iter()
+                .map(|e| (e, map.clone()))
+                .collect(),
+        )
+    }
+
+    // The constant sheaf on 0 ≤ 1 with its stalk at 1 killed.
+    fn stalk_at_zero() -> AlexandrovSheaf<TotalOrder<u32>, Rational> {
+        let mut sheaf = AlexandrovSheaf::new(TotalOrder::new(0..2), HashMap::from([(0, 1)]));
+        sheaf.set_restriction(0, 1, Matrix::zeros(0, 1));
+        sheaf
+    }
+
+    #[test]
+    fn naturality() {
+        let chain = TotalOrder::new(0..3);
+        let plane = constant(chain.clone(), 2);
+        let line = constant(chain.clone(), 1);
+        let projection = everywhere(&chain, Matrix::from_rows(vec![vec![q(1), q(0)]]));
+        assert!(projection.is_natural(&plane, &line));
+        assert!(!projection.is_natural(&line, &plane));
+        assert!(SheafMorphism::identity(&plane).is_natural(&plane, &plane));
+
+        // Scaling differently at two comparable elements does not commute
+        // with the identity restriction between them.
+        let mut components = HashMap::from([(0, Matrix::identity(1)), (1, Matrix::identity(1))]);
+        components.insert(2, Matrix::from_rows(vec![vec![q(2)]]));
+        assert!(!SheafMorphism::new(components).is_natural(&line, &line));
+    }
+
+    #[test]
+    fn composition() {
+        let chain = TotalOrder::new(0..3);
+        let plane = constant(chain.clone(), 2);
+        let swap = everywhere(
+            &chain,
+            Matrix::from_rows(vec![vec![q(0), q(1)], vec![q(1), q(0)]]),
+        );
+        assert_eq!(swap.then(&swap), SheafMorphism::identity(&plane));
+        let projection = everywhere(&chain, Matrix::from_rows(vec![vec![q(1), q(0)]]));
+        let second = swap.then(&projection);
+        assert_eq!(
+            second.component(&1),
+            Some(&Matrix::from_rows(vec![vec![q(0), q(1)]]))
+        );
+        assert!(second.is_natural(&plane, &constant(chain, 1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "same elements")]
+    fn composing_over_different_elements_panics() {
+        let identity = SheafMorphism::identity(&constant(TotalOrder::new(0..3), 1));
+        identity.then(&SheafMorphism::identity(&constant(
+            TotalOrder::new(0..2),
+            1,
+        )));
+    }
+
+    #[test]
+    #[should_panic(expected = "must start where the first one ends")]
+    fn composing_mismatched_stalks_panics() {
+        let chain = TotalOrder::new(0..3);
+        let projection = everywhere(&chain, Matrix::from_rows(vec![vec![q(1), q(0)]]));
+        projection.then(&projection);
+    }
+
+    #[test]
+    fn kernel_image_and_cokernel_of_a_projection() {
+        let chain = TotalOrder::new(0..3);
+        let plane = constant(chain.clone(), 2);
+        let line = constant(chain.clone(), 1);
+        let projection = everywhere(&chain, Matrix::from_rows(vec![vec![q(1), q(0)]]));
+
+        let (kernel, inclusion) = projection.kernel(&plane, &line);
+        assert!(kernel.is_functorial());
+        assert!(inclusion.is_natural(&kernel, &plane));
+        assert!(inclusion.then(&projection).is_zero());
+        assert_eq!(kernel.stalk(&0), 1);
+        assert_eq!(kernel.global_sections().len(), 1);
+
+        let (image, inclusion) = projection.image(&plane, &line);
+        assert!(inclusion.is_natural(&image, &line));
+        assert_eq!(image.global_sections().len(), 1);
+
+        let (cokernel, quotient) = projection.cokernel(&plane, &line);
+        assert!(quotient.is_natural(&line, &cokernel));
+        assert!(chain.elements().iter().all(|e| cokernel.stalk(e) == 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "must form a morphism")]
+    fn kernel_of_an_unnatural_map_panics() {
+        let chain = TotalOrder::new(0..3);
+        let plane = constant(chain.clone(), 2);
+        let line = constant(chain.clone(), 1);
+        let projection = everywhere(&chain, Matrix::from_rows(vec![vec![q(1), q(0)]]));
+        projection.kernel(&line, &plane);
+    }
+
+    #[test]
+    fn kernel_of_restricting_to_a_closed_point() {
+        // Mapping the constant sheaf on 0 ≤ 1 onto its stalk at 0 leaves the
+        // sheaf supported on the open set {1}, which has no global sections.
+        let chain = TotalOrder::new(0..2);
+        let constant = constant(chain, 1);
+        let target = stalk_at_zero();
+        let map = SheafMorphism::new(HashMap::from([
+            (0, Matrix::identity(1)),
+            (1, Matrix::zeros(0, 1)),
+        ]));
+        assert!(map.is_natural(&constant, &target));
+
+        let (kernel, inclusion) = map.kernel(&constant, &target);
+        assert!(kernel.is_functorial());
+        assert_eq!((kernel.stalk(&0), kernel.stalk(&1)), (0, 1));
+        assert!(kernel.global_sections().is_empty());
+        assert_eq!(kernel.sections_over(&[1].into_iter().collect()).len(), 1);
+
+        // The kernel's inclusion has the original map as its cokernel.
+        let (cokernel, quotient) = inclusion.cokernel(&kernel, &constant);
+        assert!(cokernel.is_functorial());
+        assert!(quotient.is_natural(&constant, &cokernel));
+        assert_eq!((cokernel.stalk(&0), cokernel.stalk(&1)), (1, 0));
+        assert_eq!(cokernel.global_sections().len(), 1);
+    }
+
+    #[test]
+    fn sheaves_on_a_space() {
+        let space = sierpinski();
+        let stalks = HashMap::from([('a', 1), ('b', 1)]);
+        let mut sheaf = AlexandrovSheaf::<_, Rational>::on_space(&space, stalks);
+        sheaf.set_restriction('b', 'a', Matrix::identity(1));
+        let zero = SheafMorphism::zero(&sheaf, &sheaf);
+        assert!(zero.is_natural(&sheaf, &sheaf));
+        let (kernel, inclusion) = zero.kernel(&sheaf, &sheaf);
+        assert_eq!(inclusion, SheafMorphism::identity(&sheaf));
+        assert_eq!(kernel.global_sections().len(), 1);
+    }
+
+    // Collapses the chain 0 ≤ 1 ≤ 2 onto 0 ≤ 1 by merging its top two
+    // elements.
+    fn collapse() -> MonotoneMap<TotalOrder<u32>, TotalOrder<u32>> {
+        MonotoneMap::new(|x: u32| x.min(1))
+    }
+
+    #[test]
+    fn pushforward_to_a_point_takes_global_sections() {
+        let space = FiniteTopology::from_basis(
+            HashSet::from(['a', 'b', 'c']),
+            vec![
+                HashSet::from(['a']),
+                HashSet::from(['a', 'b']),
+                HashSet::from(['c']),
+            ],
+        );
+        let point = FiniteTopology::from_basis(HashSet::from([()]), vec![HashSet::from([()])]);
+        let map = ContinuousMap::new(|_| ());
+        let stalks = HashMap::from([('a', 1), ('b', 1), ('c', 1)]);
+        let mut sheaf = AlexandrovSheaf::<_, Rational>::on_space(&space, stalks);
+        sheaf.set_restriction('b', 'a', Matrix::identity(1));
+        let pushed = sheaf.pushforward_along(&map, &point);
+        assert!(pushed.is_functorial());
+        // One section for each connected component.
+        assert_eq!(pushed.stalk(&()), 2);
+
+        let pulled = pushed.pullback_along(&map, &space);
+        assert!(pulled.is_functorial());
+        assert_eq!(pulled.stalk(&'c'), 2);
+
+        let (_, epsilon) = sheaf.adjunction_counit_along(&map, &point);
+        assert!(epsilon.is_natural(&pulled, &sheaf));
+        let (pushed_back, eta) = pushed.adjunction_unit_along(&map, &space);
+        assert!(eta.is_natural(&pushed, &pushed_back));
+    }
+
+    #[test]
+    fn pushforward_along_a_collapse() {
+        let sheaf = constant(TotalOrder::new(0..3), 1);
+        let pushed = sheaf.pushforward(&collapse(), TotalOrder::new(0..2));
+        assert!(pushed.is_functorial());
+        assert_eq!((pushed.stalk(&0), pushed.stalk(&1)), (1, 1));
+        assert_eq!(pushed.restriction(&0, &1), Some(Matrix::identity(1)));
+        // Global sections are preserved.
+        assert_eq!(
+            pushed.global_sections().len(),
+            sheaf.global_sections().len()
+        );
+
+        let pulled = stalk_at_zero().pullback(&collapse(), TotalOrder::new(0..3));
+        assert!(pulled.is_functorial());
+        assert_eq!(
+            (pulled.stalk(&0), pulled.stalk(&1), pulled.stalk(&2)),
+            (1, 0, 0)
+        );
+    }
+
+    #[test]
+    fn unit_and_counit() {
+        let chain = TotalOrder::new(0..3);
+        let base = TotalOrder::new(0..2);
+        let sheaf = stalk_at_zero();
+        let (pushed, eta) = sheaf.adjunction_unit(&collapse(), chain.clone());
+        assert!(pushed.is_functorial());
+        assert!(eta.is_natural(&sheaf, &pushed));
+
+        let mut on_chain = constant(chain.clone(), 2);
+        on_chain.set_restriction(
+            1,
+            2,
+            Matrix::from_rows(vec![vec![q(1), q(1)], vec![q(0), q(1)]]),
+        );
+        on_chain.set_restriction(
+            0,
+            2,
+            Matrix::from_rows(vec![vec![q(1), q(1)], vec![q(0), q(1)]]),
+        );
+        assert!(on_chain.is_functorial());
+        let (pulled, epsilon) = on_chain.adjunction_counit(&collapse(), base.clone());
+        assert!(pulled.is_functorial());
+        assert!(epsilon.is_natural(&pulled, &on_chain));
+
+        // The triangle identity: pulling back the unit and then applying the
+        // counit is the identity of f^* G.
+        let pulled_sheaf = sheaf.pullback(&collapse(), chain.clone());
+        let pulled_unit = SheafMorphism::new(
+            chain
+                .elements()
+                .into_iter()
+                .map(|p| (p, eta.component(&collapse().apply(p)).unwrap().clone()))
+                .collect(),
+        );
+        let (_, epsilon) = pulled_sheaf.adjunction_counit(&collapse(), base);
+        assert_eq!(
+            pulled_unit.then(&epsilon),
+            SheafMorphism::identity(&pulled_sheaf)
+        );
+    }
+}
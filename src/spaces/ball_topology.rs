@@ -0,0 +1,105 @@
+use super::*;
+use crate::spaces::finite_topology::FiniteTopology;
+
+/// The topology on a finite metric space generated by the open balls of one
+/// fixed radius.
+///
+/// Balls of every radius would make a finite space discrete, so fixing the
+/// scale is what makes the topology interesting: points closer than `radius`
+/// can no longer be separated, and the space coarsens as the radius grows.
+#[derive(Clone, Debug)]
+pub struct BallTopology<'a, X: FiniteTopologicalSpace + MetricSpace<Distance = f64>> {
+    pub space: &'a X,
+    pub radius: f64,
+    topology: FiniteTopology<X::Point>,
+}
+
+impl<'a, X: FiniteTopologicalSpace + MetricSpace<Distance = f64>> BallTopology<'a, X> {
+    pub fn new(space: &'a X, radius: f64) -> Self {
+        assert!(radius > 0.0, "Open balls need a positive radius");
+        let points = space.points();
+        let balls = points.iter().map(|p| ball(space, p, radius)).collect();
+        Self {
+            space,
+            radius,
+            topology: FiniteTopology::from_subbasis(points, balls),
+        }
+    }
+
+    /// The points at distance less than `radius` from `center`.
+    pub fn ball(&self, center: &X::Point) -> HashSet<X::Point> {
+        ball(self.space, center, self.radius)
+    }
+}
+
+fn ball<X: FiniteTopologicalSpace + MetricSpace<Distance = f64>>(
+    space: &X,
+    center: &X::Point,
+    radius: f64,
+) -> HashSet<X::Point> {
+    space
+        .points()
+        .into_iter()
+        .filter(|p| space.distance(center.clone(), p.clone()) < radius)
+        .collect()
+}
+
+impl<X: FiniteTopologicalSpace + MetricSpace<Distance = f64>> TopologicalSpace
+    for BallTopology<'_, X>
+{
+    type Point = X::Point;
+    type OpenSet = HashSet<X::Point>;
+
+    fn points(&self) -> HashSet<Self::Point> {
+        self.topology.points()
+    }
+
+    fn neighborhood(&self, point: Self::Point) -> Self::OpenSet {
+        self.topology.neighborhood(point)
+    }
+
+    fn is_open(&self, set: Self::OpenSet) -> bool {
+        self.topology.is_open(set)
+    }
+}
+
+impl<X: FiniteTopologicalSpace + MetricSpace<Distance = f64>> FiniteTopologicalSpace
+    for BallTopology<'_, X>
+{
+    fn minimal_neighborhood(&self, point: Self::Point) -> HashSet<Self::Point> {
+        self.topology.minimal_neighborhood(point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{metrics::Euclidean, spaces::point_cloud::PointCloud};
+
+    fn line() -> PointCloud<Vec<f64>, Euclidean> {
+        PointCloud::new(vec![vec![0.0], vec![1.0], vec![2.0], vec![10.0]], Euclidean)
+    }
+
+    #[test]
+    fn small_balls_are_discrete() {
+        let cloud = line();
+        let topology = BallTopology::new(&cloud, 0.5);
+        assert_eq!(topology.open_sets().len(), 16);
+        assert_eq!(topology.connected_components().len(), 4);
+    }
+
+    #[test]
+    fn larger_balls_coarsen() {
+        let cloud = line();
+        let topology = BallTopology::new(&cloud, 1.5);
+        assert_eq!(topology.ball(&1), HashSet::from([0, 1, 2]));
+        // The middle point lies in every ball around its neighbours.
+        assert_eq!(topology.minimal_neighborhood(1), HashSet::from([1]));
+        assert_eq!(topology.minimal_neighborhood(0), HashSet::from([0, 1]));
+        assert!(!topology.is_open(HashSet::from([0])));
+        assert_eq!(topology.connected_components().len(), 2);
+
+        let everything = BallTopology::new(&cloud, 20.0);
+        assert_eq!(everything.open_sets().len(), 2);
+    }
+}
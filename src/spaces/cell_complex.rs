@@ -2,18 +2,78 @@
 
 use std::{collections::HashSet, hash::Hash, rc::Rc};
 
-use crate::topology::{OpenSet, PreSheaf, Section, Sheaf, TopologicalSpace};
+use super::collapse::{collapse_sequence, Collapse};
+use crate::topology::{
+    FiniteTopologicalSpace, OpenSet, PreSheaf, Section, Sheaf, TopologicalSpace,
+};
 
-#[derive(Clone, Hash, PartialEq, Eq)]
-pub struct Point<T: Eq + Hash + Clone>(T);
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Point<T: Eq + Hash + Clone>(pub T);
 
 /// Trait for an n-cell in a cell complex. Inherits from TopologicalSpace, and
-/// adds the cell's identification map.
+/// adds the cell's dimension and identification map.
 pub trait Cell<T: Eq + Hash + Clone>:
     TopologicalSpace<Point = Point<T>, OpenSet = HashSet<Point<T>>>
 {
     fn identification(&self, skeleton: &Skeleton<T>) -> HashSet<<Self as TopologicalSpace>::Point>; // Should return a set of points in the cell identified with previous skeleton
                                                                                                     // points, and thus shouldn't be included in the next skeleton.
+
+    /// The smallest open set of the cell containing `point`, which is the
+    /// intersection of all of them. By default every set of points of the
+    /// cell containing `point` is checked with `is_open`, which takes time
+    /// exponential in the size of the cell, so cells that know their smallest
+    /// neighborhoods should say so.
+    fn minimal_neighborhood(&self, point: Point<T>) -> HashSet<Point<T>> {
+        let others: Vec<Point<T>> = self.points().into_iter().filter(|p| *p != point).collect();
+        assert!(
+            others.len() < usize::BITS as usize,
+            "Cell is too large to search for open sets",
+        );
+        let mut smallest: HashSet<Point<T>> = others.iter().cloned().collect();
+        for chosen in 0..1usize << others.len() {
+            let candidate: HashSet<Point<T>> = (0..others.len())
+                .filter(|i| chosen & (1 << i) != 0)
+                .map(|i| others[i].clone())
+                .collect();
+            if !candidate.is_superset(&smallest) {
+                let mut open = candidate.clone();
+                open.insert(point.clone());
+                if self.is_open(open) {
+                    smallest = smallest.intersection(&candidate).cloned().collect();
+                }
+            }
+        }
+        smallest.insert(point);
+        smallest
+    }
+
+    /// The dimension of the cell, used to find its faces when collapsing. By
+    /// default it is the length of the longest chain of points, each lying in
+    /// the closure of the next but not the other way round, which is the
+    /// dimension of a finite model of a cell.
+    fn dim(&self) -> usize {
+        let mut neighborhoods: Vec<HashSet<Point<T>>> = self
+            .points()
+            .into_iter()
+            .map(|p| self.minimal_neighborhood(p))
+            .collect();
+        // A point lies strictly below every point whose smallest neighborhood
+        // is strictly inside its own, so smaller neighborhoods come first.
+        neighborhoods.sort_by_key(|n| n.len());
+        let mut heights: Vec<usize> = Vec::with_capacity(neighborhoods.len());
+        for (i, neighborhood) in neighborhoods.iter().enumerate() {
+            let height = (0..i)
+                .filter(|&j| {
+                    neighborhoods[j].len() < neighborhood.len()
+                        && neighborhoods[j].is_subset(neighborhood)
+                })
+                .map(|j| heights[j] + 1)
+                .max()
+                .unwrap_or(0);
+            heights.push(height);
+        }
+        heights.into_iter().max().unwrap_or(0)
+    }
 }
 
 /// A skeleton is a collection of cells, glued together by their identification
@@ -26,6 +86,12 @@ pub struct Skeleton<T: Eq + Hash + Clone> {
     pub children: Vec<Skeleton<T>>,
 }
 
+impl<T: Eq + Hash + Clone> Default for Skeleton<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T: Eq + Hash + Clone> Skeleton<T> {
     pub fn new() -> Self {
         let cells = Vec::new();
@@ -80,16 +146,134 @@ impl<T: Eq + Hash + Clone> CellComplex<T> {
             dim,
         }
     }
+
+    // A cell is a face of another when it has lower dimension and all of its
+    // points lie in the other cell. Returns, for each cell, the indices of the
+    // cells it is a proper face of.
+    pub(crate) fn cofaces(&self) -> Vec<Vec<usize>> {
+        let points: Vec<HashSet<Point<T>>> = self.cells.iter().map(|c| c.points()).collect();
+        (0..self.cells.len())
+            .map(|i| {
+                (0..self.cells.len())
+                    .filter(|&j| {
+                        self.cells[i].dim() < self.cells[j].dim() && points[i].is_subset(&points[j])
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Greedily performs elementary collapses until no free face remains. The
+    /// returned collapses refer to indices of `cells` before the call.
+    pub fn collapse(&mut self) -> Vec<Collapse<usize>> {
+        let dims: Vec<usize> = self.cells.iter().map(|c| c.dim()).collect();
+        let (sequence, alive) = collapse_sequence(&dims, &self.cofaces());
+        let mut alive = alive.into_iter();
+        self.cells.retain(|_| alive.next().unwrap());
+        let remaining = self.points();
+        self.points.retain(|p| remaining.contains(p));
+        self.dim = self.cells.iter().map(|c| c.dim()).max().unwrap_or(0);
+        sequence
+            .into_iter()
+            .map(|(face, coface)| Collapse { face, coface })
+            .collect()
+    }
+
+    /// Checks whether the complex collapses to a single 0-cell.
+    pub fn collapses_to_point(&self) -> bool {
+        let dims: Vec<usize> = self.cells.iter().map(|c| c.dim()).collect();
+        let (_, alive) = collapse_sequence(&dims, &self.cofaces());
+        let survivors: Vec<usize> = (0..dims.len()).filter(|&i| alive[i]).collect();
+        matches!(survivors[..], [cell] if dims[cell] == 0)
+    }
+
+    /// The product cell structure, with one cell `a × b` of dimension
+    /// `dim(a) + dim(b)` for every pair of cells. Cells are included in
+    /// increasing dimension so that each is glued onto the lower skeleton.
+    pub fn product<U: Eq + Hash + Clone + 'static>(
+        &self,
+        other: &CellComplex<U>,
+    ) -> CellComplex<(T, U)>
+    where
+        T: 'static,
+    {
+        let mut cells: Vec<ProductCell<T, U>> = Vec::new();
+        for left in &self.cells {
+            for right in &other.cells {
+                cells.push(ProductCell {
+                    left: left.clone(),
+                    right: right.clone(),
+                });
+            }
+        }
+        cells.sort_by_key(|c| c.dim());
+        let mut skeleton = Skeleton::new();
+        for cell in cells {
+            skeleton.include_cell(Rc::new(cell));
+        }
+        CellComplex::new(skeleton, self.dim + other.dim)
+    }
 }
 
-/// Implements OpenSets as HashSet<Point<T>> for the cell complex topology.
-impl<T: Eq + Hash + Clone> OpenSet for HashSet<Point<T>> {
-    type Point = Point<T>;
-    fn intersect(&self, other: Self) -> Self {
-        self.intersection(&other).cloned().collect()
+/// The product of two cells, with the product topology.
+pub struct ProductCell<T: Eq + Hash + Clone, U: Eq + Hash + Clone> {
+    #[allow(clippy::type_complexity)]
+    pub left: Rc<dyn Cell<T, Point = Point<T>, OpenSet = HashSet<Point<T>>>>,
+    #[allow(clippy::type_complexity)]
+    pub right: Rc<dyn Cell<U, Point = Point<U>, OpenSet = HashSet<Point<U>>>>,
+}
+
+impl<T: Eq + Hash + Clone, U: Eq + Hash + Clone> ProductCell<T, U> {
+    fn pairs(a: HashSet<Point<T>>, b: HashSet<Point<U>>) -> HashSet<Point<(T, U)>> {
+        a.iter()
+            .flat_map(|p| b.iter().map(move |q| Point((p.0.clone(), q.0.clone()))))
+            .collect()
+    }
+}
+
+impl<T: Eq + Hash + Clone, U: Eq + Hash + Clone> TopologicalSpace for ProductCell<T, U> {
+    type Point = Point<(T, U)>;
+    type OpenSet = HashSet<Point<(T, U)>>;
+
+    fn points(&self) -> HashSet<Self::Point> {
+        Self::pairs(self.left.points(), self.right.points())
+    }
+
+    fn neighborhood(&self, point: Self::Point) -> Self::OpenSet {
+        let Point((a, b)) = point;
+        Self::pairs(
+            self.left.neighborhood(Point(a)),
+            self.right.neighborhood(Point(b)),
+        )
+    }
+
+    fn is_open(&self, set: Self::OpenSet) -> bool {
+        let points = self.points();
+        set.iter()
+            .all(|p| points.contains(p) && self.minimal_neighborhood(p.clone()).is_subset(&set))
+    }
+}
+
+impl<T: Eq + Hash + Clone, U: Eq + Hash + Clone> Cell<(T, U)> for ProductCell<T, U> {
+    fn dim(&self) -> usize {
+        self.left.dim() + self.right.dim()
     }
-    fn union(&self, other: Self) -> Self {
-        self.union(&other).cloned().collect()
+
+    // Points already present belong to the boundary, which is made of
+    // products of lower dimensional cells.
+    fn identification(&self, skeleton: &Skeleton<(T, U)>) -> HashSet<Point<(T, U)>> {
+        self.points()
+            .intersection(&skeleton.points)
+            .cloned()
+            .collect()
+    }
+
+    fn minimal_neighborhood(&self, point: Point<(T, U)>) -> HashSet<Point<(T, U)>> {
+        let Point((a, b)) = point;
+        Self::pairs(
+            self.left.minimal_neighborhood(Point(a)),
+            self.right.minimal_neighborhood(Point(b)),
+        )
     }
 }
 
@@ -133,6 +317,28 @@ impl<T: Eq + Hash + Clone> TopologicalSpace for CellComplex<T> {
     }
 }
 
+impl<T: Eq + Hash + Clone> FiniteTopologicalSpace for CellComplex<T> {
+    // A set is open when it meets every cell in an open set, so the smallest
+    // neighborhood grows by the smallest neighborhoods in each cell until it
+    // is stable.
+    fn minimal_neighborhood(&self, point: Self::Point) -> HashSet<Self::Point> {
+        let mut neighborhood = HashSet::from([point]);
+        loop {
+            let mut grown = neighborhood.clone();
+            for cell in &self.cells {
+                let points = cell.points();
+                for p in neighborhood.iter().filter(|p| points.contains(p)) {
+                    grown.extend(cell.minimal_neighborhood(p.clone()));
+                }
+            }
+            if grown == neighborhood {
+                return neighborhood;
+            }
+            neighborhood = grown;
+        }
+    }
+}
+
 /// This implements the Presheaf conditions for the cell complex topology.
 impl<T: Eq + Hash + Clone, S: Section> PreSheaf<S> for CellComplex<T> {
     type TopologicalSpace = CellComplex<T>;
@@ -200,8 +406,11 @@ impl<T: Eq + Hash + Clone, S: Section> Sheaf<S> for CellComplex<T> {
 }
 
 #[cfg(test)]
+// The original test cell spells out every case of `is_open`.
+#[allow(clippy::needless_return, clippy::if_same_then_else)]
 mod tests {
     use super::*;
+    use crate::test_support::{circle, complex_from, interval};
 
     #[test]
     fn build_basic_complex() {
@@ -271,4 +480,96 @@ mod tests {
         });
         skeleton_0.include_cell(first_cell);
     }
+
+    // A cell whose open sets are those containing 0, but which only offers
+    // the whole cell as a neighborhood.
+    struct PointedCell(HashSet<Point<i32>>);
+
+    impl TopologicalSpace for PointedCell {
+        type Point = Point<i32>;
+        type OpenSet = HashSet<Point<i32>>;
+        fn points(&self) -> HashSet<Self::Point> {
+            self.0.clone()
+        }
+        fn neighborhood(&self, _point: Self::Point) -> Self::OpenSet {
+            self.0.clone()
+        }
+        fn is_open(&self, set: Self::OpenSet) -> bool {
+            set.is_subset(&self.0) && (set.is_empty() || set.contains(&Point(0)))
+        }
+    }
+
+    impl Cell<i32> for PointedCell {
+        fn identification(&self, _skeleton: &Skeleton<i32>) -> HashSet<Point<i32>> {
+            HashSet::new()
+        }
+    }
+
+    #[test]
+    fn smallest_neighborhoods_come_from_is_open() {
+        let cell = PointedCell((0..3).map(Point).collect());
+        assert_eq!(
+            cell.minimal_neighborhood(Point(0)),
+            HashSet::from([Point(0)])
+        );
+        assert_eq!(
+            cell.minimal_neighborhood(Point(2)),
+            HashSet::from([Point(0), Point(2)])
+        );
+        // Every other point lies in the closure of 0.
+        assert_eq!(cell.dim(), 1);
+        let mut skeleton = Skeleton::new();
+        skeleton.include_cell(Rc::new(cell));
+        let complex = CellComplex::new(skeleton, 1);
+        assert_eq!(complex.open_sets().len(), 5);
+        assert_eq!(
+            complex.closure(&HashSet::from([Point(1)])),
+            HashSet::from([Point(1)])
+        );
+    }
+
+    #[test]
+    fn interval_collapses() {
+        let mut complex = interval();
+        assert!(complex.collapses_to_point());
+        let sequence = complex.collapse();
+        assert_eq!(sequence, vec![Collapse { face: 0, coface: 2 }]);
+        assert_eq!(complex.cells.len(), 1);
+        assert_eq!(complex.dim, 0);
+    }
+
+    #[test]
+    fn lone_edge_is_not_a_point() {
+        let complex = complex_from(vec![(vec![0, 1], 1)]);
+        assert!(complex.cofaces()[0].is_empty());
+        assert!(!complex.collapses_to_point());
+    }
+
+    #[test]
+    fn product_of_intervals_is_a_square() {
+        let square = interval().product(&interval());
+        assert_eq!(square.cells.len(), 9);
+        assert_eq!(square.dim, 2);
+        assert_eq!(square.points().len(), 4);
+        let dims: Vec<usize> = square.cells.iter().map(|c| c.dim()).collect();
+        assert_eq!(dims, vec![0, 0, 0, 0, 1, 1, 1, 1, 2]);
+        assert!(square.collapses_to_point());
+    }
+
+    #[test]
+    fn cylinder_does_not_collapse_to_point() {
+        let mut cylinder = interval().product(&circle());
+        assert_eq!(cylinder.cells.len(), 12);
+        assert!(!cylinder.collapses_to_point());
+        cylinder.collapse();
+        assert_eq!(cylinder.cells.len(), 4);
+    }
+
+    #[test]
+    fn circle_does_not_collapse() {
+        let mut complex = circle();
+        assert!(!complex.collapses_to_point());
+        assert!(complex.collapse().is_empty());
+        assert_eq!(complex.cells.len(), 4);
+    }
 }
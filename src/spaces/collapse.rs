@@ -0,0 +1,67 @@
+/// A single elementary collapse: the free `face` is removed together with the
+/// unique `coface` that contains it.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Collapse<C> {
+    pub face: C,
+    pub coface: C,
+}
+
+/// Greedily performs elementary collapses on an abstract face structure.
+///
+/// Cells are given by index, `dims[i]` is the dimension of cell `i` and
+/// `cofaces[i]` lists every cell that properly contains cell `i`. A cell is
+/// free when exactly one of its cofaces is still present and that coface is
+/// exactly one dimension higher. Returns the collapses as `(face, coface)`
+/// index pairs, in the order they were performed, together with the cells
+/// that survive.
+pub(crate) fn collapse_sequence(
+    dims: &[usize],
+    cofaces: &[Vec<usize>],
+) -> (Vec<(usize, usize)>, Vec<bool>) {
+    let mut alive = vec![true; dims.len()];
+    let mut sequence = Vec::new();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for face in 0..dims.len() {
+            if !alive[face] {
+                continue;
+            }
+            let mut remaining = cofaces[face].iter().filter(|&&c| alive[c]);
+            if let (Some(&coface), None) = (remaining.next(), remaining.next()) {
+                if dims[coface] == dims[face] + 1 {
+                    alive[face] = false;
+                    alive[coface] = false;
+                    sequence.push((face, coface));
+                    changed = true;
+                }
+            }
+        }
+    }
+    (sequence, alive)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collapses_an_edge() {
+        // Two vertices and the edge between them.
+        let dims = vec![0, 0, 1];
+        let cofaces = vec![vec![2], vec![2], vec![]];
+        let (sequence, alive) = collapse_sequence(&dims, &cofaces);
+        assert_eq!(sequence, vec![(0, 2)]);
+        assert_eq!(alive, vec![false, true, false]);
+    }
+
+    #[test]
+    fn hollow_triangle_does_not_collapse() {
+        // Three vertices and three edges forming a circle.
+        let dims = vec![0, 0, 0, 1, 1, 1];
+        let cofaces = vec![vec![3, 5], vec![3, 4], vec![4, 5], vec![], vec![], vec![]];
+        let (sequence, alive) = collapse_sequence(&dims, &cofaces);
+        assert!(sequence.is_empty());
+        assert!(alive.into_iter().all(|x| x));
+    }
+}
@@ -0,0 +1,464 @@
+use std::{collections::HashMap, hash::Hash, rc::Rc};
+
+use super::{
+    cell_complex::{Cell, CellComplex, Point, Skeleton},
+    *,
+};
+use crate::topology::ContinuousMap;
+
+/// Points of a disjoint union, a wedge sum or a mapping cylinder, tagged with
+/// the complex they come from.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Sum<T, U> {
+    Left(T),
+    Right(U),
+}
+
+/// Points of a cone: the points of the base and one apex.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Cone<T> {
+    Base(T),
+    Apex,
+}
+
+/// Points of a suspension: the points of the base and two apexes.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Suspension<T> {
+    Base(T),
+    North,
+    South,
+}
+
+/// A cell given by the smallest open neighborhood of each of its points. It
+/// is glued onto the points that are already in the skeleton.
+pub struct FiniteCell<T: Eq + Hash + Clone> {
+    dim: usize,
+    neighborhoods: HashMap<Point<T>, HashSet<Point<T>>>,
+}
+
+impl<T: Eq + Hash + Clone> FiniteCell<T> {
+    /// Every point has to lie in its own neighborhood, and the neighborhood of
+    /// a point has to contain the neighborhoods of all of its points.
+    pub fn new(dim: usize, neighborhoods: HashMap<Point<T>, HashSet<Point<T>>>) -> Self {
+        for (point, neighborhood) in &neighborhoods {
+            assert!(
+                neighborhood.contains(point),
+                "A point must lie in its own neighborhood",
+            );
+            assert!(
+                neighborhood.iter().all(|q| neighborhoods
+                    .get(q)
+                    .is_some_and(|n| n.is_subset(neighborhood))),
+                "Neighborhoods must be closed under taking neighborhoods of their points",
+            );
+        }
+        Self { dim, neighborhoods }
+    }
+
+    // The cell with its points renamed by an injective map.
+    fn relabel<U: Eq + Hash + Clone>(
+        cell: &DynCell<U>,
+        dim: usize,
+        label: &impl Fn(&U) -> T,
+    ) -> Self {
+        let neighborhoods = cell
+            .points()
+            .into_iter()
+            .map(|p| {
+                let neighborhood = cell
+                    .minimal_neighborhood(p.clone())
+                    .iter()
+                    .map(|q| Point(label(&q.0)))
+                    .collect();
+                (Point(label(&p.0)), neighborhood)
+            })
+            .collect();
+        Self { dim, neighborhoods }
+    }
+
+    // The cone over the cell, whose apex lies in the closure of no other
+    // point and has the whole cone as its only neighborhood.
+    fn cone<U: Eq + Hash + Clone>(cell: &DynCell<U>, label: &impl Fn(&U) -> T, apex: T) -> Self {
+        let mut cone = Self::relabel(cell, cell.dim() + 1, label);
+        let mut everything: HashSet<Point<T>> = cone.neighborhoods.keys().cloned().collect();
+        everything.insert(Point(apex.clone()));
+        cone.neighborhoods.insert(Point(apex), everything);
+        cone
+    }
+
+    fn vertex(point: T) -> Self {
+        let point = Point(point);
+        Self {
+            dim: 0,
+            neighborhoods: HashMap::from([(point.clone(), HashSet::from([point]))]),
+        }
+    }
+}
+
+impl<T: Eq + Hash + Clone> TopologicalSpace for FiniteCell<T> {
+    type Point = Point<T>;
+    type OpenSet = HashSet<Point<T>>;
+
+    fn points(&self) -> HashSet<Self::Point> {
+        self.neighborhoods.keys().cloned().collect()
+    }
+
+    fn neighborhood(&self, point: Self::Point) -> Self::OpenSet {
+        self.neighborhoods.get(&point).cloned().unwrap_or_default()
+    }
+
+    fn is_open(&self, set: Self::OpenSet) -> bool {
+        set.iter()
+            .all(|p| self.neighborhoods.get(p).is_some_and(|n| n.is_subset(&set)))
+    }
+}
+
+impl<T: Eq + Hash + Clone> Cell<T> for FiniteCell<T> {
+    fn dim(&self) -> usize {
+        self.dim
+    }
+
+    fn identification(&self, skeleton: &Skeleton<T>) -> HashSet<Point<T>> {
+        self.points()
+            .intersection(&skeleton.points)
+            .cloned()
+            .collect()
+    }
+
+    fn minimal_neighborhood(&self, point: Point<T>) -> HashSet<Point<T>> {
+        self.neighborhood(point)
+    }
+}
+
+type DynCell<T> = Rc<dyn Cell<T, Point = Point<T>, OpenSet = HashSet<Point<T>>>>;
+
+// Glues the cells in increasing dimension.
+fn assemble<T: Eq + Hash + Clone + 'static>(mut cells: Vec<FiniteCell<T>>) -> CellComplex<T> {
+    cells.sort_by_key(|c| c.dim);
+    let dim = cells.iter().map(|c| c.dim).max().unwrap_or(0);
+    let mut skeleton = Skeleton::new();
+    for cell in cells {
+        skeleton.include_cell(Rc::new(cell));
+    }
+    CellComplex::new(skeleton, dim)
+}
+
+impl<T: Eq + Hash + Clone + 'static> CellComplex<T> {
+    fn relabeled<V: Eq + Hash + Clone>(&self, label: impl Fn(&T) -> V) -> Vec<FiniteCell<V>> {
+        self.cells
+            .iter()
+            .map(|c| FiniteCell::relabel(c, c.dim(), &label))
+            .collect()
+    }
+
+    fn cone_cells<V: Eq + Hash + Clone>(
+        &self,
+        label: impl Fn(&T) -> V,
+        apex: V,
+    ) -> Vec<FiniteCell<V>> {
+        let mut cells: Vec<FiniteCell<V>> = self
+            .cells
+            .iter()
+            .map(|c| FiniteCell::cone(c, &label, apex.clone()))
+            .collect();
+        cells.push(FiniteCell::vertex(apex));
+        cells
+    }
+
+    // One cell of dimension `dim(c) + 1` for every cell `c`, made of `c` and
+    // its image. A point `x` lies in the neighborhood of `y` in the codomain
+    // exactly when `f(x)` does.
+    fn cylinder_cells<U: Eq + Hash + Clone + 'static, V: Eq + Hash + Clone>(
+        &self,
+        codomain: &CellComplex<U>,
+        map: &ContinuousMap<CellComplex<T>, CellComplex<U>>,
+        left: impl Fn(&T) -> V,
+        right: impl Fn(&U) -> V,
+    ) -> Vec<FiniteCell<V>> {
+        assert!(
+            map.is_continuous(self, codomain),
+            "The map must be continuous"
+        );
+        let mut neighborhoods_in_codomain = HashMap::new();
+        self.cells
+            .iter()
+            .map(|cell| {
+                let mut cylinder = FiniteCell::relabel(cell, cell.dim() + 1, &left);
+                let points = cell.points();
+                let image = map.image(&points);
+                for y in &image {
+                    let around: &HashSet<Point<U>> = neighborhoods_in_codomain
+                        .entry(y.clone())
+                        .or_insert_with(|| codomain.minimal_neighborhood(y.clone()));
+                    let neighborhood = image
+                        .iter()
+                        .filter(|z| around.contains(z))
+                        .map(|z| Point(right(&z.0)))
+                        .chain(
+                            points
+                                .iter()
+                                .filter(|x| around.contains(&map.apply((*x).clone())))
+                                .map(|x| Point(left(&x.0))),
+                        )
+                        .collect();
+                    cylinder
+                        .neighborhoods
+                        .insert(Point(right(&y.0)), neighborhood);
+                }
+                cylinder
+            })
+            .collect()
+    }
+
+    /// The disjoint union, with the cells of both complexes side by side.
+    pub fn disjoint_union<U: Eq + Hash + Clone + 'static>(
+        &self,
+        other: &CellComplex<U>,
+    ) -> CellComplex<Sum<T, U>> {
+        let mut cells = self.relabeled(|t| Sum::Left(t.clone()));
+        cells.extend(other.relabeled(|u| Sum::Right(u.clone())));
+        assemble(cells)
+    }
+
+    /// The wedge sum, gluing `other_basepoint` to `basepoint`. The glued point
+    /// is `Sum::Left(basepoint)`.
+    pub fn wedge<U: Eq + Hash + Clone + 'static>(
+        &self,
+        basepoint: &T,
+        other: &CellComplex<U>,
+        other_basepoint: &U,
+    ) -> CellComplex<Sum<T, U>> {
+        assert!(
+            self.points().contains(&Point(basepoint.clone()))
+                && other.points().contains(&Point(other_basepoint.clone())),
+            "Basepoints must be points of the complexes",
+        );
+        let mut cells = self.relabeled(|t| Sum::Left(t.clone()));
+        let glued = Point(Sum::Left(basepoint.clone()));
+        // Both 0-cells at the basepoints become a single one.
+        let shared = |c: &FiniteCell<Sum<T, U>>| c.dim == 0 && c.neighborhoods.contains_key(&glued);
+        let has_vertex = cells.iter().any(shared);
+        cells.extend(
+            other
+                .relabeled(|u| {
+                    if u == other_basepoint {
+                        Sum::Left(basepoint.clone())
+                    } else {
+                        Sum::Right(u.clone())
+                    }
+                })
+                .into_iter()
+                .filter(|c| !(has_vertex && shared(c))),
+        );
+        assemble(cells)
+    }
+
+    /// The non-Hausdorff cone, with a cell `c * apex` of dimension `dim(c) + 1`
+    /// for every cell `c` and a 0-cell for the apex. It always collapses to a
+    /// point.
+    pub fn cone(&self) -> CellComplex<Cone<T>> {
+        let mut cells = self.relabeled(|t| Cone::Base(t.clone()));
+        cells.extend(self.cone_cells(|t| Cone::Base(t.clone()), Cone::Apex));
+        assemble(cells)
+    }
+
+    /// The union of two cones over the complex along their common base.
+    pub fn suspension(&self) -> CellComplex<Suspension<T>> {
+        let mut cells = self.relabeled(|t| Suspension::Base(t.clone()));
+        cells.extend(self.cone_cells(|t| Suspension::Base(t.clone()), Suspension::North));
+        cells.extend(self.cone_cells(|t| Suspension::Base(t.clone()), Suspension::South));
+        assemble(cells)
+    }
+
+    /// The non-Hausdorff mapping cylinder of a continuous map, containing
+    /// this complex as `Sum::Left` and the codomain as `Sum::Right`. It
+    /// deformation retracts onto the codomain.
+    pub fn mapping_cylinder<U: Eq + Hash + Clone + 'static>(
+        &self,
+        codomain: &CellComplex<U>,
+        map: &ContinuousMap<CellComplex<T>, CellComplex<U>>,
+    ) -> CellComplex<Sum<T, U>> {
+        let mut cells = self.cylinder_cells(
+            codomain,
+            map,
+            |t| Sum::Left(t.clone()),
+            |u| Sum::Right(u.clone()),
+        );
+        cells.extend(self.relabeled(|t| Sum::Left(t.clone())));
+        cells.extend(codomain.relabeled(|u| Sum::Right(u.clone())));
+        assemble(cells)
+    }
+
+    /// The mapping cylinder with the end of this complex coned off.
+    pub fn mapping_cone<U: Eq + Hash + Clone + 'static>(
+        &self,
+        codomain: &CellComplex<U>,
+        map: &ContinuousMap<CellComplex<T>, CellComplex<U>>,
+    ) -> CellComplex<Sum<Cone<T>, U>> {
+        let base = |t: &T| Sum::Left(Cone::Base(t.clone()));
+        let mut cells = self.cylinder_cells(codomain, map, base, |u| Sum::Right(u.clone()));
+        cells.extend(self.relabeled(base));
+        cells.extend(self.cone_cells(base, Sum::Left(Cone::Apex)));
+        cells.extend(codomain.relabeled(|u| Sum::Right(u.clone())));
+        assemble(cells)
+    }
+}
+
+impl<T: Eq + Hash + Clone + 'static, U: Eq + Hash + Clone + 'static> Sum<T, U> {
+    /// The inclusion of the left complex into a disjoint union, wedge sum or
+    /// mapping cylinder.
+    pub fn left_inclusion() -> ContinuousMap<CellComplex<T>, CellComplex<Sum<T, U>>> {
+        ContinuousMap::new(|p: Point<T>| Point(Sum::Left(p.0)))
+    }
+
+    /// The inclusion of the right complex into a disjoint union, mapping
+    /// cylinder or mapping cone.
+    pub fn right_inclusion() -> ContinuousMap<CellComplex<U>, CellComplex<Sum<T, U>>> {
+        ContinuousMap::new(|p: Point<U>| Point(Sum::Right(p.0)))
+    }
+
+    /// The inclusion of the right complex into a wedge sum, sending
+    /// `other_basepoint` to the glued point.
+    pub fn wedge_inclusion(
+        basepoint: T,
+        other_basepoint: U,
+    ) -> ContinuousMap<CellComplex<U>, CellComplex<Sum<T, U>>> {
+        ContinuousMap::new(move |p: Point<U>| {
+            if p.0 == other_basepoint {
+                Point(Sum::Left(basepoint.clone()))
+            } else {
+                Point(Sum::Right(p.0))
+            }
+        })
+    }
+}
+
+impl<T: Eq + Hash + Clone + 'static> Cone<T> {
+    /// The inclusion of a complex as the base of its cone.
+    pub fn base_inclusion() -> ContinuousMap<CellComplex<T>, CellComplex<Cone<T>>> {
+        ContinuousMap::new(|p: Point<T>| Point(Cone::Base(p.0)))
+    }
+}
+
+impl<T: Eq + Hash + Clone + 'static> Suspension<T> {
+    /// The inclusion of a complex as the equator of its suspension.
+    pub fn base_inclusion() -> ContinuousMap<CellComplex<T>, CellComplex<Suspension<T>>> {
+        ContinuousMap::new(|p: Point<T>| Point(Suspension::Base(p.0)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{circle, complex_from, interval};
+
+    fn euler_characteristic<T: Eq + Hash + Clone>(complex: &CellComplex<T>) -> i64 {
+        complex
+            .cells
+            .iter()
+            .map(|c| if c.dim() % 2 == 0 { 1 } else { -1 })
+            .sum()
+    }
+
+    #[test]
+    fn disjoint_union_keeps_cells_apart() {
+        let union = interval().disjoint_union(&circle());
+        assert_eq!(union.cells.len(), 7);
+        assert_eq!(union.points().len(), 6);
+        assert_eq!(union.dim, 1);
+        assert_eq!(euler_characteristic(&union), 1);
+        let left = Sum::<i32, i32>::left_inclusion();
+        let right = Sum::<i32, i32>::right_inclusion();
+        assert!(left.is_continuous(&interval(), &union));
+        assert!(right.is_continuous(&circle(), &union));
+        // The cells are discrete, so only cones are connected.
+        assert_eq!(union.connected_components().len(), 6);
+        let cones = interval().cone().disjoint_union(&circle().cone());
+        assert_eq!(cones.connected_components().len(), 2);
+    }
+
+    #[test]
+    fn wedge_of_circles() {
+        let wedge = circle().wedge(&0, &circle(), &1);
+        assert_eq!(wedge.points().len(), 7);
+        assert_eq!(wedge.cells.len(), 7);
+        assert_eq!(euler_characteristic(&wedge), -1);
+        assert!(!wedge.collapses_to_point());
+        assert!(Sum::<i32, i32>::left_inclusion().is_continuous(&circle(), &wedge));
+        assert!(Sum::wedge_inclusion(0, 1).is_continuous(&circle(), &wedge));
+        assert!(!Sum::<i32, i32>::right_inclusion().is_continuous(&circle(), &wedge));
+    }
+
+    #[test]
+    fn cones_are_contractible() {
+        let cone = circle().cone();
+        assert_eq!(cone.dim, 2);
+        assert_eq!(cone.cells.len(), 9);
+        assert_eq!(euler_characteristic(&cone), 1);
+        assert!(cone.collapses_to_point());
+        assert_eq!(cone.minimal_neighborhood(Point(Cone::Apex)), cone.points());
+        assert!(Cone::base_inclusion().is_continuous(&circle(), &cone));
+    }
+
+    #[test]
+    fn suspension_of_two_points_is_a_circle() {
+        let sphere = complex_from(vec![(vec![0], 0), (vec![1], 0)]);
+        let circle = sphere.suspension();
+        assert_eq!(circle.cells.len(), 8);
+        assert_eq!(circle.points().len(), 4);
+        assert_eq!(euler_characteristic(&circle), 0);
+        assert!(!circle.collapses_to_point());
+        assert!(Suspension::base_inclusion().is_continuous(&sphere, &circle));
+        assert_eq!(euler_characteristic(&circle.suspension()), 2);
+    }
+
+    #[test]
+    fn mapping_cylinder_retracts_onto_codomain() {
+        let point = complex_from(vec![(vec![7], 0)]);
+        let constant = ContinuousMap::new(|_: Point<i32>| Point(7));
+        let cylinder = interval().mapping_cylinder(&point, &constant);
+        assert_eq!(cylinder.cells.len(), 7);
+        assert_eq!(cylinder.dim, 2);
+        assert!(cylinder.collapses_to_point());
+        assert_eq!(
+            cylinder.minimal_neighborhood(Point(Sum::Right(7))),
+            cylinder.points()
+        );
+        assert!(Sum::<i32, i32>::left_inclusion().is_continuous(&interval(), &cylinder));
+        assert!(Sum::<i32, i32>::right_inclusion().is_continuous(&point, &cylinder));
+    }
+
+    #[test]
+    fn mapping_cone_of_identity_is_contractible() {
+        let identity = ContinuousMap::identity();
+        let cone = circle().mapping_cone(&circle(), &identity);
+        assert_eq!(euler_characteristic(&cone), 1);
+        assert!(cone.collapses_to_point());
+        let inclusion = Cone::base_inclusion().then(&Sum::<Cone<i32>, i32>::left_inclusion());
+        assert!(inclusion.is_continuous(&circle(), &cone));
+        assert!(Sum::<Cone<i32>, i32>::right_inclusion().is_continuous(&circle(), &cone));
+    }
+
+    #[test]
+    fn mapping_cone_of_circle_to_point_is_a_suspension() {
+        let point = complex_from(vec![(vec![7], 0)]);
+        let constant = ContinuousMap::new(|_: Point<i32>| Point(7));
+        let cone = circle().mapping_cone(&point, &constant);
+        assert_eq!(euler_characteristic(&cone), 2);
+        assert!(!cone.collapses_to_point());
+    }
+
+    #[test]
+    #[should_panic]
+    fn discontinuous_maps_are_rejected() {
+        let swap = ContinuousMap::new(|p: Point<i32>| Point(1 - p.0));
+        let sierpinski = assemble(vec![FiniteCell::new(
+            1,
+            HashMap::from([
+                (Point(0), HashSet::from([Point(0)])),
+                (Point(1), HashSet::from([Point(0), Point(1)])),
+            ]),
+        )]);
+        sierpinski.mapping_cylinder(&sierpinski, &swap);
+    }
+}
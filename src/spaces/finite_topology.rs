@@ -0,0 +1,197 @@
+use std::hash::Hash;
+
+use super::*;
+
+/// A topology on a finite set of points, generated by a basis or a subbasis.
+///
+/// In a finite space every point has a smallest open neighborhood, namely the
+/// intersection of all generating sets that contain it, and a set is open
+/// exactly when it contains the smallest neighborhood of each of its points.
+/// Only these neighborhoods are stored; [`FiniteTopologicalSpace::open_sets`]
+/// generates the whole topology on demand.
+#[derive(Clone, Debug)]
+pub struct FiniteTopology<P: Eq + Hash + Clone> {
+    points: HashSet<P>,
+    minimal_neighborhoods: HashMap<P, HashSet<P>>,
+}
+
+impl<P: Eq + Hash + Clone> FiniteTopology<P> {
+    /// Builds the topology whose open sets are the unions of basis elements.
+    /// The basis has to cover the points, and every point in the intersection
+    /// of two basis elements has to lie in a basis element contained in that
+    /// intersection.
+    pub fn from_basis(points: HashSet<P>, basis: Vec<HashSet<P>>) -> Self {
+        assert!(
+            points.iter().all(|p| basis.iter().any(|b| b.contains(p))),
+            "The basis must cover every point",
+        );
+        for (i, a) in basis.iter().enumerate() {
+            for b in &basis[i + 1..] {
+                let intersection: HashSet<P> = a.intersection(b).cloned().collect();
+                assert!(
+                    intersection.iter().all(|p| basis
+                        .iter()
+                        .any(|c| c.contains(p) && c.is_subset(&intersection))),
+                    "The intersection of two basis elements must be a union of basis elements",
+                );
+            }
+        }
+        Self::generated_by(points, &basis)
+    }
+
+    /// Builds the coarsest topology in which every subbasis element is open,
+    /// closing the subbasis under finite intersections and arbitrary unions.
+    pub fn from_subbasis(points: HashSet<P>, subbasis: Vec<HashSet<P>>) -> Self {
+        Self::generated_by(points, &subbasis)
+    }
+
+    fn generated_by(points: HashSet<P>, generators: &[HashSet<P>]) -> Self {
+        assert!(
+            generators.iter().all(|g| g.is_subset(&points)),
+            "Generating sets must only contain points of the space",
+        );
+        let minimal_neighborhoods = points
+            .iter()
+            .map(|p| {
+                // The empty intersection is the whole space.
+                let neighborhood = generators
+                    .iter()
+                    .filter(|g| g.contains(p))
+                    .fold(points.clone(), |acc, g| {
+                        acc.intersection(g).cloned().collect()
+                    });
+                (p.clone(), neighborhood)
+            })
+            .collect();
+        Self {
+            points,
+            minimal_neighborhoods,
+        }
+    }
+
+    /// The smallest open sets containing each point. They form the coarsest
+    /// basis of the topology.
+    pub fn basis(&self) -> Vec<HashSet<P>> {
+        let mut basis: Vec<HashSet<P>> = Vec::new();
+        for neighborhood in self.minimal_neighborhoods.values() {
+            if !basis.contains(neighborhood) {
+                basis.push(neighborhood.clone());
+            }
+        }
+        basis
+    }
+}
+
+impl<P: Eq + Hash + Clone> TopologicalSpace for FiniteTopology<P> {
+    type Point = P;
+    type OpenSet = HashSet<P>;
+
+    fn points(&self) -> HashSet<Self::Point> {
+        self.points.clone()
+    }
+
+    fn neighborhood(&self, point: Self::Point) -> Self::OpenSet {
+        self.minimal_neighborhoods
+            .get(&point)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn is_open(&self, set: Self::OpenSet) -> bool {
+        set.iter().all(|p| {
+            self.minimal_neighborhoods
+                .get(p)
+                .is_some_and(|n| n.is_subset(&set))
+        })
+    }
+}
+
+impl<P: Eq + Hash + Clone> FiniteTopologicalSpace for FiniteTopology<P> {
+    fn minimal_neighborhood(&self, point: Self::Point) -> HashSet<Self::Point> {
+        self.neighborhood(point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{set, sierpinski};
+
+    #[test]
+    fn sierpinski_space() {
+        let space = sierpinski();
+        assert!(space.is_open(set(&[])));
+        assert!(space.is_open(set(&['a'])));
+        assert!(!space.is_open(set(&['b'])));
+        assert!(space.is_open(set(&['a', 'b'])));
+        assert_eq!(space.neighborhood('b'), set(&['a', 'b']));
+        assert_eq!(space.open_sets().len(), 3);
+    }
+
+    #[test]
+    fn subbasis_closes_under_intersections() {
+        let space = FiniteTopology::from_subbasis(
+            set(&['a', 'b', 'c']),
+            vec![set(&['a', 'b']), set(&['b', 'c'])],
+        );
+        assert!(space.is_open(set(&['b'])));
+        assert!(!space.is_open(set(&['a', 'c'])));
+        let open_sets = space.open_sets();
+        assert_eq!(open_sets.len(), 5);
+        assert!(open_sets.contains(&set(&['a', 'b', 'c'])));
+        assert!(open_sets.iter().all(|o| space.is_open(o.clone())));
+    }
+
+    #[test]
+    fn uncovered_points_see_everything() {
+        let space = FiniteTopology::from_subbasis(set(&['a', 'b']), vec![set(&['a'])]);
+        assert_eq!(space.neighborhood('b'), set(&['a', 'b']));
+        assert!(!space.is_open(set(&['b'])));
+    }
+
+    #[test]
+    fn foreign_points_are_not_open() {
+        let space = FiniteTopology::from_subbasis(set(&['a']), vec![]);
+        assert!(!space.is_open(set(&['z'])));
+        assert_eq!(space.basis(), vec![set(&['a'])]);
+    }
+
+    #[test]
+    fn closure_interior_boundary() {
+        let space = FiniteTopology::from_subbasis(
+            set(&['a', 'b', 'c']),
+            vec![set(&['a']), set(&['a', 'b'])],
+        );
+        assert_eq!(space.closure(&set(&['b'])), set(&['b', 'c']));
+        assert_eq!(space.interior(&set(&['a', 'c'])), set(&['a']));
+        assert_eq!(space.boundary(&set(&['a', 'c'])), set(&['b', 'c']));
+        assert_eq!(space.derived_set(&set(&['a'])), set(&['b', 'c']));
+        assert!(space.is_closed(&set(&['b', 'c'])));
+        assert!(!space.is_closed(&set(&['b'])));
+        assert!(space.is_dense(&set(&['a'])));
+        assert!(!space.is_dense(&set(&['c'])));
+    }
+
+    #[test]
+    fn components_follow_minimal_neighborhoods() {
+        let space = FiniteTopology::from_subbasis(
+            set(&['a', 'b', 'c', 'd']),
+            vec![set(&['a']), set(&['a', 'b']), set(&['c']), set(&['c', 'd'])],
+        );
+        let mut components = space.connected_components();
+        components.sort_by_key(|c| c.contains(&'c'));
+        assert_eq!(components, vec![set(&['a', 'b']), set(&['c', 'd'])]);
+        assert_eq!(space.path_components().len(), 2);
+        assert!(!space.is_connected());
+        assert!(sierpinski().is_connected());
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_basis() {
+        FiniteTopology::from_basis(
+            set(&['a', 'b', 'c']),
+            vec![set(&['a', 'b']), set(&['b', 'c'])],
+        );
+    }
+}
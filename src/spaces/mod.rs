@@ -1,6 +1,15 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::topology::{MetricSpace, PreSheaf, TopologicalSpace};
+use crate::topology::{FiniteTopologicalSpace, MetricSpace, PreSheaf, TopologicalSpace};
 
-mod cell_complex;
-mod undirected_graph;
+pub mod ball_topology;
+pub mod cell_complex;
+pub mod collapse;
+pub mod constructions;
+pub mod finite_topology;
+pub mod point_cloud;
+pub mod product;
+pub mod quotient;
+pub mod simplicial_complex;
+pub mod subspace;
+pub mod undirected_graph;
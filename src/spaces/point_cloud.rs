@@ -0,0 +1,73 @@
+use super::*;
+use crate::metrics::Metric;
+
+/// A finite sample of values with a metric on them. Points are indices into
+/// the sample, and the topology is discrete, as for every finite metric space.
+pub struct PointCloud<T, M: Metric<T>> {
+    pub values: Vec<T>,
+    pub metric: M,
+}
+
+impl<T, M: Metric<T>> PointCloud<T, M> {
+    pub fn new(values: Vec<T>, metric: M) -> Self {
+        Self { values, metric }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl<T, M: Metric<T>> TopologicalSpace for PointCloud<T, M> {
+    type Point = usize;
+    type OpenSet = HashSet<usize>;
+
+    fn points(&self) -> HashSet<usize> {
+        (0..self.values.len()).collect()
+    }
+
+    fn neighborhood(&self, point: usize) -> HashSet<usize> {
+        HashSet::from([point])
+    }
+
+    fn is_open(&self, set: HashSet<usize>) -> bool {
+        set.iter().all(|&p| p < self.values.len())
+    }
+}
+
+impl<T, M: Metric<T>> FiniteTopologicalSpace for PointCloud<T, M> {
+    fn minimal_neighborhood(&self, point: usize) -> HashSet<usize> {
+        self.neighborhood(point)
+    }
+}
+
+impl<T, M: Metric<T>> MetricSpace for PointCloud<T, M> {
+    type Distance = f64;
+
+    fn distance(&self, point_a: usize, point_b: usize) -> f64 {
+        self.metric
+            .distance(&self.values[point_a], &self.values[point_b])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::{Euclidean, Levenshtein};
+
+    #[test]
+    fn distances_between_samples() {
+        let cloud = PointCloud::new(vec![vec![0.0, 0.0], vec![3.0, 4.0]], Euclidean);
+        assert_eq!(cloud.distance(0, 1), 5.0);
+        assert_eq!(cloud.len(), 2);
+        assert!(cloud.is_open(HashSet::from([1])));
+        assert_eq!(cloud.connected_components().len(), 2);
+
+        let words = PointCloud::new(vec!["sheaf".to_string(), "shelf".to_string()], Levenshtein);
+        assert_eq!(words.distance(0, 1), 1.0);
+    }
+}
@@ -0,0 +1,168 @@
+use std::hash::Hash;
+
+use super::*;
+
+/// How the distances in the two factors are combined into a distance on the
+/// product.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ProductMetric {
+    #[default]
+    Max,
+    L1,
+    L2,
+}
+
+/// The product of two spaces. Its points are pairs and its topology is
+/// generated by products of open sets.
+pub struct Product<X: TopologicalSpace, Y: TopologicalSpace> {
+    pub left: X,
+    pub right: Y,
+    pub metric: ProductMetric,
+}
+
+impl<X: TopologicalSpace, Y: TopologicalSpace> Product<X, Y> {
+    pub fn new(left: X, right: Y) -> Self {
+        Self {
+            left,
+            right,
+            metric: ProductMetric::default(),
+        }
+    }
+
+    pub fn with_metric(mut self, metric: ProductMetric) -> Self {
+        self.metric = metric;
+        self
+    }
+}
+
+// All pairs of a point of `a` with a point of `b`.
+fn pairs<P: Eq + Hash + Clone, Q: Eq + Hash + Clone>(
+    a: impl IntoIterator<Item = P>,
+    b: impl IntoIterator<Item = Q> + Clone,
+) -> HashSet<(P, Q)> {
+    a.into_iter()
+        .flat_map(|p| b.clone().into_iter().map(move |q| (p.clone(), q)))
+        .collect()
+}
+
+impl<X: FiniteTopologicalSpace, Y: FiniteTopologicalSpace> TopologicalSpace for Product<X, Y> {
+    type Point = (X::Point, Y::Point);
+    type OpenSet = HashSet<Self::Point>;
+
+    fn points(&self) -> HashSet<Self::Point> {
+        pairs(self.left.points(), self.right.points())
+    }
+
+    fn neighborhood(&self, point: Self::Point) -> Self::OpenSet {
+        pairs(
+            self.left.neighborhood(point.0),
+            self.right.neighborhood(point.1),
+        )
+    }
+
+    // A set is open when it contains a basic open box around each of its
+    // points, and the smallest such box is the product of the smallest
+    // neighborhoods.
+    fn is_open(&self, set: Self::OpenSet) -> bool {
+        let (left, right) = (self.left.points(), self.right.points());
+        set.iter().all(|(a, b)| {
+            left.contains(a)
+                && right.contains(b)
+                && self
+                    .minimal_neighborhood((a.clone(), b.clone()))
+                    .is_subset(&set)
+        })
+    }
+}
+
+impl<X: FiniteTopologicalSpace, Y: FiniteTopologicalSpace> FiniteTopologicalSpace
+    for Product<X, Y>
+{
+    fn minimal_neighborhood(&self, point: Self::Point) -> HashSet<Self::Point> {
+        pairs(
+            self.left.minimal_neighborhood(point.0),
+            self.right.minimal_neighborhood(point.1),
+        )
+    }
+}
+
+impl<X, Y> MetricSpace for Product<X, Y>
+where
+    X: FiniteTopologicalSpace + MetricSpace<Distance = f64>,
+    Y: FiniteTopologicalSpace + MetricSpace<Distance = f64>,
+{
+    type Distance = f64;
+
+    fn distance(
+        &self,
+        point_a: <Self as TopologicalSpace>::Point,
+        point_b: <Self as TopologicalSpace>::Point,
+    ) -> Self::Distance {
+        let a = self.left.distance(point_a.0, point_b.0);
+        let b = self.right.distance(point_a.1, point_b.1);
+        match self.metric {
+            ProductMetric::Max => a.max(b),
+            ProductMetric::L1 => a + b,
+            ProductMetric::L2 => a.hypot(b),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sierpinski;
+
+    // Integers on the real line, with the discrete topology.
+    struct Line(HashSet<i32>);
+
+    impl TopologicalSpace for Line {
+        type Point = i32;
+        type OpenSet = HashSet<i32>;
+        fn points(&self) -> HashSet<i32> {
+            self.0.clone()
+        }
+        fn neighborhood(&self, point: i32) -> HashSet<i32> {
+            vec![point].into_iter().collect()
+        }
+        fn is_open(&self, set: HashSet<i32>) -> bool {
+            set.is_subset(&self.0)
+        }
+    }
+
+    impl FiniteTopologicalSpace for Line {
+        fn minimal_neighborhood(&self, point: i32) -> HashSet<i32> {
+            self.neighborhood(point)
+        }
+    }
+
+    impl MetricSpace for Line {
+        type Distance = f64;
+        fn distance(&self, point_a: i32, point_b: i32) -> f64 {
+            f64::from((point_a - point_b).abs())
+        }
+    }
+
+    #[test]
+    fn product_topology() {
+        let square = Product::new(sierpinski(), sierpinski());
+        assert_eq!(square.points().len(), 4);
+        assert!(square.is_open(vec![('a', 'a')].into_iter().collect()));
+        assert!(!square.is_open(vec![('a', 'b')].into_iter().collect()));
+        assert!(square.is_open(vec![('a', 'a'), ('a', 'b')].into_iter().collect()));
+        assert_eq!(square.minimal_neighborhood(('b', 'b')), square.points());
+        // The open sets are the up-sets of a two by two grid.
+        assert_eq!(square.open_sets().len(), 6);
+    }
+
+    #[test]
+    fn product_metrics() {
+        let line = || Line((0..5).collect());
+        let plane = Product::new(line(), line());
+        assert_eq!(plane.distance((0, 0), (3, 4)), 4.0);
+        let plane = plane.with_metric(ProductMetric::L1);
+        assert_eq!(plane.distance((0, 0), (3, 4)), 7.0);
+        let plane = plane.with_metric(ProductMetric::L2);
+        assert_eq!(plane.distance((0, 0), (3, 4)), 5.0);
+    }
+}
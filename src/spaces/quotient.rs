@@ -0,0 +1,195 @@
+use super::*;
+use crate::{topology::ContinuousMap, union_find::UnionFind};
+
+/// The quotient of a space by an equivalence relation. Its points are the
+/// equivalence classes, numbered from zero, and a set of classes is open
+/// exactly when the union of its classes is open in the original space.
+pub struct Quotient<'a, X: FiniteTopologicalSpace> {
+    pub ambient: &'a X,
+    classes: Vec<HashSet<X::Point>>,
+    class_of: HashMap<X::Point, usize>,
+}
+
+impl<'a, X: FiniteTopologicalSpace> Quotient<'a, X> {
+    /// Builds the quotient whose classes are the given parts. The parts must be
+    /// nonempty, pairwise disjoint, and cover the space.
+    pub fn from_partition(ambient: &'a X, partition: Vec<HashSet<X::Point>>) -> Self {
+        let mut class_of = HashMap::new();
+        for (index, part) in partition.iter().enumerate() {
+            assert!(!part.is_empty(), "Classes must be nonempty");
+            for point in part {
+                assert!(
+                    class_of.insert(point.clone(), index).is_none(),
+                    "Classes must be pairwise disjoint",
+                );
+            }
+        }
+        let points = ambient.points();
+        assert!(
+            class_of.len() == points.len() && points.iter().all(|p| class_of.contains_key(p)),
+            "Classes must cover exactly the points of the space",
+        );
+        Self {
+            ambient,
+            classes: partition,
+            class_of,
+        }
+    }
+
+    /// Builds the quotient by the smallest equivalence relation in which
+    /// `related` points are identified. The relation does not have to be
+    /// reflexive, symmetric or transitive.
+    pub fn from_relation(ambient: &'a X, related: impl Fn(&X::Point, &X::Point) -> bool) -> Self {
+        let points: Vec<X::Point> = ambient.points().into_iter().collect();
+        let mut sets = UnionFind::new(points.len());
+        for (i, a) in points.iter().enumerate() {
+            for (j, b) in points.iter().enumerate() {
+                if related(a, b) {
+                    sets.union(i, j);
+                }
+            }
+        }
+        let partition = sets
+            .sets()
+            .into_iter()
+            .map(|set| set.into_iter().map(|i| points[i].clone()).collect())
+            .collect();
+        Self::from_partition(ambient, partition)
+    }
+
+    pub fn classes(&self) -> &[HashSet<X::Point>] {
+        &self.classes
+    }
+
+    /// The class containing `point`, if it is a point of the space.
+    pub fn class_of(&self, point: &X::Point) -> Option<usize> {
+        self.class_of.get(point).copied()
+    }
+
+    /// The union of the given classes, as a set in the original space.
+    pub fn preimage(&self, set: &HashSet<usize>) -> HashSet<X::Point> {
+        set.iter()
+            .filter_map(|&c| self.classes.get(c))
+            .flatten()
+            .cloned()
+            .collect()
+    }
+
+    /// The map sending each point to its class.
+    pub fn projection(&self) -> ContinuousMap<X, Self>
+    where
+        X::Point: 'static,
+        X::OpenSet: FromIterator<X::Point>,
+    {
+        let class_of = self.class_of.clone();
+        ContinuousMap::new(move |p| class_of[&p])
+    }
+}
+
+impl<X: FiniteTopologicalSpace> TopologicalSpace for Quotient<'_, X>
+where
+    X::OpenSet: FromIterator<X::Point>,
+{
+    type Point = usize;
+    type OpenSet = HashSet<usize>;
+
+    fn points(&self) -> HashSet<Self::Point> {
+        (0..self.classes.len()).collect()
+    }
+
+    fn neighborhood(&self, point: Self::Point) -> Self::OpenSet {
+        self.minimal_neighborhood(point)
+    }
+
+    fn is_open(&self, set: Self::OpenSet) -> bool {
+        set.iter().all(|&c| c < self.classes.len())
+            && self
+                .ambient
+                .is_open(self.preimage(&set).into_iter().collect())
+    }
+}
+
+impl<X: FiniteTopologicalSpace> FiniteTopologicalSpace for Quotient<'_, X>
+where
+    X::OpenSet: FromIterator<X::Point>,
+{
+    // The smallest saturated open set containing a class: grow the class by
+    // the smallest neighborhoods of its points and by the classes they meet
+    // until nothing changes.
+    fn minimal_neighborhood(&self, point: Self::Point) -> HashSet<Self::Point> {
+        let mut neighborhood: HashSet<usize> = vec![point].into_iter().collect();
+        loop {
+            let grown: HashSet<usize> = self
+                .preimage(&neighborhood)
+                .into_iter()
+                .flat_map(|p| self.ambient.minimal_neighborhood(p))
+                .map(|p| self.class_of[&p])
+                .collect();
+            if grown == neighborhood {
+                return neighborhood;
+            }
+            neighborhood = grown;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        spaces::{finite_topology::FiniteTopology, undirected_graph::UndirectedGraph},
+        test_support::set,
+    };
+
+    #[test]
+    fn identifying_open_points() {
+        let space =
+            FiniteTopology::from_subbasis(set(&['a', 'b', 'c']), vec![set(&['a']), set(&['b'])]);
+        let quotient =
+            Quotient::from_relation(&space, |x, y| matches!((x, y), ('a', 'b') | ('b', 'a')));
+        assert_eq!(quotient.classes().len(), 2);
+        let ab = quotient.class_of(&'a').unwrap();
+        let c = quotient.class_of(&'c').unwrap();
+        assert_eq!(quotient.class_of(&'b'), Some(ab));
+        // The quotient is the Sierpinski space.
+        assert!(quotient.is_open(vec![ab].into_iter().collect()));
+        assert!(!quotient.is_open(vec![c].into_iter().collect()));
+        assert_eq!(quotient.minimal_neighborhood(c), quotient.points());
+        assert!(quotient.projection().is_continuous(&space, &quotient));
+    }
+
+    #[test]
+    fn neighborhoods_are_saturated() {
+        // Gluing 'b' to 'c' forces the neighborhood of 'a' to grow.
+        let space = FiniteTopology::from_subbasis(
+            set(&['a', 'b', 'c']),
+            vec![set(&['a', 'b']), set(&['c'])],
+        );
+        let partition = vec![set(&['a']), set(&['b', 'c'])];
+        let quotient = Quotient::from_partition(&space, partition);
+        // Neither class is open, so the quotient is indiscrete.
+        assert_eq!(quotient.minimal_neighborhood(0), quotient.points());
+        assert_eq!(quotient.minimal_neighborhood(1), quotient.points());
+        assert!(!quotient.is_open(vec![1].into_iter().collect()));
+        assert!(!quotient.is_open(vec![0].into_iter().collect()));
+    }
+
+    #[test]
+    fn identify_graph_vertices_by_parity() {
+        let graph = UndirectedGraph::new((0..6).collect(), HashSet::new());
+        let quotient = Quotient::from_relation(&graph, |a, b| a % 2 == b % 2);
+        assert_eq!(quotient.points().len(), 2);
+        let evens = quotient.class_of(&0).unwrap();
+        assert_eq!(
+            quotient.preimage(&vec![evens].into_iter().collect()),
+            vec![0, 2, 4].into_iter().collect()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn overlapping_partition() {
+        let space = FiniteTopology::from_subbasis(set(&['a', 'b']), vec![]);
+        Quotient::from_partition(&space, vec![set(&['a', 'b']), set(&['b'])]);
+    }
+}
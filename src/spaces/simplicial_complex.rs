@@ -0,0 +1,318 @@
+use std::{collections::BTreeSet, hash::Hash};
+
+use super::collapse::{collapse_sequence, Collapse};
+use crate::linear_algebra::{Field, Matrix};
+
+/// An abstract simplicial complex. Simplices are stored as sorted vectors of
+/// vertices and the complex is always closed under taking faces.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SimplicialComplex<V: Ord + Hash + Clone> {
+    simplices: BTreeSet<Vec<V>>,
+}
+
+impl<V: Ord + Hash + Clone> Default for SimplicialComplex<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Ord + Hash + Clone> SimplicialComplex<V> {
+    pub fn new() -> Self {
+        Self {
+            simplices: BTreeSet::new(),
+        }
+    }
+
+    /// Builds the smallest complex containing every given simplex.
+    pub fn from_simplices<I: IntoIterator<Item = Vec<V>>>(simplices: I) -> Self {
+        let mut complex = Self::new();
+        for simplex in simplices {
+            complex.add_simplex(simplex);
+        }
+        complex
+    }
+
+    // Adds a simplex together with all of its faces.
+    pub fn add_simplex(&mut self, simplex: Vec<V>) {
+        let simplex = normalize(simplex);
+        if simplex.is_empty() || self.simplices.contains(&simplex) {
+            return;
+        }
+        for face in proper_faces(&simplex) {
+            self.simplices.insert(face);
+        }
+        self.simplices.insert(simplex);
+    }
+
+    // Removes a simplex together with every simplex containing it, so the
+    // result is still a complex.
+    pub fn remove_simplex(&mut self, simplex: &[V]) {
+        let simplex = normalize(simplex.to_vec());
+        self.simplices.retain(|s| !is_face(&simplex, s));
+    }
+
+    pub fn contains(&self, simplex: &[V]) -> bool {
+        self.simplices.contains(&normalize(simplex.to_vec()))
+    }
+
+    pub fn simplices(&self) -> impl Iterator<Item = &Vec<V>> {
+        self.simplices.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.simplices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.simplices.is_empty()
+    }
+
+    /// The dimension of the complex, or `None` if it is empty.
+    pub fn dimension(&self) -> Option<usize> {
+        self.simplices.iter().map(|s| s.len() - 1).max()
+    }
+
+    /// The alternating count of simplices by dimension.
+    pub fn euler_characteristic(&self) -> i64 {
+        self.simplices
+            .iter()
+            .map(|s| if s.len() % 2 == 1 { 1 } else { -1 })
+            .sum()
+    }
+
+    pub fn vertices(&self) -> Vec<V> {
+        self.simplices_of_dim(0)
+            .into_iter()
+            .map(|mut s| s.remove(0))
+            .collect()
+    }
+
+    pub fn simplices_of_dim(&self, dim: usize) -> Vec<Vec<V>> {
+        self.simplices
+            .iter()
+            .filter(|s| s.len() == dim + 1)
+            .cloned()
+            .collect()
+    }
+
+    /// The maximal simplices, i.e. those that are not a face of any other.
+    pub fn facets(&self) -> Vec<Vec<V>> {
+        self.simplices
+            .iter()
+            .filter(|s| self.cofaces(s).is_empty())
+            .cloned()
+            .collect()
+    }
+
+    /// The codimension one faces of a simplex.
+    pub fn boundary_faces(simplex: &[V]) -> Vec<Vec<V>> {
+        if simplex.len() < 2 {
+            return Vec::new();
+        }
+        (0..simplex.len())
+            .map(|i| {
+                let mut face = simplex.to_vec();
+                face.remove(i);
+                face
+            })
+            .collect()
+    }
+
+    /// The matrix of the boundary map from `dim`-chains to `(dim - 1)`-chains.
+    /// Rows and columns follow the order of [`Self::simplices_of_dim`], and
+    /// the boundary of a `dim`-simplex is zero when `dim` is zero.
+    pub fn boundary_matrix<F: Field>(&self, dim: usize) -> Matrix<F> {
+        let columns = self.simplices_of_dim(dim);
+        if dim == 0 {
+            return Matrix::zeros(0, columns.len());
+        }
+        let rows = self.simplices_of_dim(dim - 1);
+        let mut matrix = Matrix::zeros(rows.len(), columns.len());
+        for (j, simplex) in columns.iter().enumerate() {
+            let mut sign = F::one();
+            for face in Self::boundary_faces(simplex) {
+                let i = rows.binary_search(&face).unwrap();
+                matrix.set(i, j, sign);
+                sign = -sign;
+            }
+        }
+        matrix
+    }
+
+    /// Every simplex of the complex that properly contains `simplex`.
+    pub fn cofaces(&self, simplex: &[V]) -> Vec<Vec<V>> {
+        self.simplices
+            .iter()
+            .filter(|s| s.len() > simplex.len() && is_face(simplex, s))
+            .cloned()
+            .collect()
+    }
+
+    /// Greedily performs elementary collapses until no free face remains,
+    /// returning the collapses in the order they were performed.
+    ///
+    /// Greedy collapsing is exact for complexes of dimension at most two. In
+    /// higher dimensions a collapsible complex may get stuck, so a complex
+    /// that does not reduce to a point is not necessarily non-collapsible.
+    pub fn collapse(&mut self) -> Vec<Collapse<Vec<V>>> {
+        let simplices: Vec<Vec<V>> = self.simplices.iter().cloned().collect();
+        let index = |s: &Vec<V>| simplices.binary_search(s).unwrap();
+        let dims: Vec<usize> = simplices.iter().map(|s| s.len() - 1).collect();
+        let mut cofaces = vec![Vec::new(); simplices.len()];
+        for (i, simplex) in simplices.iter().enumerate() {
+            for face in proper_faces(simplex) {
+                cofaces[index(&face)].push(i);
+            }
+        }
+
+        let (sequence, alive) = collapse_sequence(&dims, &cofaces);
+        self.simplices = simplices
+            .iter()
+            .zip(alive)
+            .filter(|(_, a)| *a)
+            .map(|(s, _)| s.clone())
+            .collect();
+        sequence
+            .into_iter()
+            .map(|(face, coface)| Collapse {
+                face: simplices[face].clone(),
+                coface: simplices[coface].clone(),
+            })
+            .collect()
+    }
+
+    /// Checks whether the complex collapses to a single vertex.
+    pub fn collapses_to_point(&self) -> bool {
+        let mut complex = self.clone();
+        complex.collapse();
+        complex.len() == 1
+    }
+
+    /// Repeatedly removes dominated vertices, returning them in the order they
+    /// were removed. A vertex `v` is dominated by `w` when every maximal
+    /// simplex containing `v` also contains `w`. Removing such a vertex is a
+    /// strong collapse and preserves the strong homotopy type.
+    pub fn strong_collapse(&mut self) -> Vec<V> {
+        let mut removed = Vec::new();
+        'outer: loop {
+            let facets = self.facets();
+            for vertex in self.vertices() {
+                let containing: Vec<&Vec<V>> =
+                    facets.iter().filter(|f| f.contains(&vertex)).collect();
+                let dominated = containing[0]
+                    .iter()
+                    .filter(|w| **w != vertex)
+                    .any(|w| containing.iter().all(|f| f.contains(w)));
+                if dominated {
+                    self.remove_simplex(std::slice::from_ref(&vertex));
+                    removed.push(vertex);
+                    continue 'outer;
+                }
+            }
+            return removed;
+        }
+    }
+}
+
+fn normalize<V: Ord>(mut simplex: Vec<V>) -> Vec<V> {
+    simplex.sort();
+    simplex.dedup();
+    simplex
+}
+
+// Whether the sorted simplex `face` is contained in the sorted simplex
+// `simplex`.
+fn is_face<V: Ord>(face: &[V], simplex: &[V]) -> bool {
+    face.iter().all(|v| simplex.binary_search(v).is_ok())
+}
+
+// All nonempty proper faces of a sorted simplex.
+fn proper_faces<V: Clone>(simplex: &[V]) -> Vec<Vec<V>> {
+    let n = simplex.len();
+    (1..(1_usize << n) - 1)
+        .map(|mask| {
+            (0..n)
+                .filter(|i| mask & (1 << i) != 0)
+                .map(|i| simplex[i].clone())
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn closure_under_faces() {
+        let complex = SimplicialComplex::from_simplices(vec![vec![2, 1, 3]]);
+        assert_eq!(complex.len(), 7);
+        assert_eq!(complex.dimension(), Some(2));
+        assert!(complex.contains(&[1, 3]));
+        assert_eq!(complex.facets(), vec![vec![1, 2, 3]]);
+    }
+
+    #[test]
+    fn remove_takes_cofaces() {
+        let mut complex = SimplicialComplex::from_simplices(vec![vec![1, 2, 3]]);
+        complex.remove_simplex(&[1, 2]);
+        assert!(!complex.contains(&[1, 2, 3]));
+        assert!(!complex.contains(&[1, 2]));
+        assert!(complex.contains(&[1]));
+        assert_eq!(complex.len(), 5);
+    }
+
+    #[test]
+    fn boundary_squares_to_zero() {
+        use crate::linear_algebra::Z2;
+
+        let complex = SimplicialComplex::from_simplices(vec![vec![0, 1, 2, 3]]);
+        for dim in 1..3 {
+            let d1 = complex.boundary_matrix::<Z2>(dim);
+            let d2 = complex.boundary_matrix::<Z2>(dim + 1);
+            assert!((&d1 * &d2).is_zero());
+        }
+    }
+
+    #[test]
+    fn filled_triangle_collapses() {
+        let mut complex = SimplicialComplex::from_simplices(vec![vec![1, 2, 3]]);
+        assert!(complex.collapses_to_point());
+        let sequence = complex.collapse();
+        assert_eq!(sequence.len(), 3);
+        assert_eq!(complex.len(), 1);
+    }
+
+    #[test]
+    fn circle_does_not_collapse() {
+        let mut complex =
+            SimplicialComplex::from_simplices(vec![vec![1, 2], vec![2, 3], vec![1, 3]]);
+        assert!(!complex.collapses_to_point());
+        assert!(complex.collapse().is_empty());
+        assert_eq!(complex.len(), 6);
+    }
+
+    #[test]
+    fn tree_with_circle_collapses_to_circle() {
+        let mut complex = SimplicialComplex::from_simplices(vec![
+            vec![1, 2],
+            vec![2, 3],
+            vec![1, 3],
+            vec![3, 4],
+            vec![4, 5],
+        ]);
+        complex.collapse();
+        assert_eq!(complex.simplices_of_dim(1).len(), 3);
+        assert_eq!(complex.simplices_of_dim(0).len(), 3);
+    }
+
+    #[test]
+    fn strong_collapse_of_cone() {
+        // The cone over a circle is strong collapsible.
+        let mut complex =
+            SimplicialComplex::from_simplices(vec![vec![0, 1, 2], vec![0, 2, 3], vec![0, 1, 3]]);
+        let removed = complex.strong_collapse();
+        assert_eq!(removed.len(), 3);
+        assert_eq!(complex.len(), 1);
+    }
+}
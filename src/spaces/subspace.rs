@@ -0,0 +1,168 @@
+use super::*;
+use crate::topology::{ContinuousMap, Section, Sheaf};
+
+/// A subset of a topological space with the subspace topology: its open sets
+/// are the intersections of the subset with the open sets of the ambient
+/// space.
+pub struct Subspace<'a, X: FiniteTopologicalSpace> {
+    pub ambient: &'a X,
+    points: HashSet<X::Point>,
+}
+
+impl<'a, X: FiniteTopologicalSpace> Subspace<'a, X>
+where
+    X::OpenSet: FromIterator<X::Point>,
+{
+    pub fn new(ambient: &'a X, points: HashSet<X::Point>) -> Self {
+        assert!(
+            points.is_subset(&ambient.points()),
+            "A subspace must only contain points of the ambient space",
+        );
+        Self { ambient, points }
+    }
+
+    // Intersects a set of the ambient space with the subspace.
+    fn intersect<I: IntoIterator<Item = X::Point>>(&self, set: I) -> X::OpenSet {
+        set.into_iter()
+            .filter(|p| self.points.contains(p))
+            .collect()
+    }
+
+    /// The inclusion of the subspace into the ambient space.
+    pub fn inclusion(&self) -> ContinuousMap<Self, X> {
+        ContinuousMap::new(|p| p)
+    }
+
+    /// Restricts a section of a sheaf on the ambient space to the subspace.
+    pub fn restrict_section<S: Section<TopologicalSpace = X>>(&self, section: &S) -> S {
+        section.restrict(self.points.iter().cloned().collect())
+    }
+}
+
+impl<X: FiniteTopologicalSpace> TopologicalSpace for Subspace<'_, X>
+where
+    X::OpenSet: FromIterator<X::Point>,
+{
+    type Point = X::Point;
+    type OpenSet = X::OpenSet;
+
+    fn points(&self) -> HashSet<Self::Point> {
+        self.points.clone()
+    }
+
+    fn neighborhood(&self, point: Self::Point) -> Self::OpenSet {
+        self.intersect(self.ambient.neighborhood(point))
+    }
+
+    // A set is open when, for each of its points, the smallest ambient
+    // neighborhood meets the subspace inside the set.
+    fn is_open(&self, set: Self::OpenSet) -> bool {
+        let set: HashSet<Self::Point> = set.into_iter().collect();
+        set.is_subset(&self.points)
+            && set.iter().all(|p| {
+                self.intersect(self.ambient.minimal_neighborhood(p.clone()))
+                    .into_iter()
+                    .all(|q| set.contains(&q))
+            })
+    }
+}
+
+impl<X: FiniteTopologicalSpace> FiniteTopologicalSpace for Subspace<'_, X>
+where
+    X::OpenSet: FromIterator<X::Point>,
+{
+    fn minimal_neighborhood(&self, point: Self::Point) -> HashSet<Self::Point> {
+        self.intersect(self.ambient.minimal_neighborhood(point))
+            .into_iter()
+            .collect()
+    }
+}
+
+/// A presheaf on the ambient space restricts to the subspace by only looking
+/// at the part of each open set that lies in the subspace.
+impl<X, S> PreSheaf<S> for Subspace<'_, X>
+where
+    X: FiniteTopologicalSpace + PreSheaf<S>,
+    X::OpenSet: FromIterator<X::Point>,
+    S: Section<TopologicalSpace = X>,
+{
+    type TopologicalSpace = X;
+
+    fn restriction(&self, set_to: &X::OpenSet, section: &S) -> S {
+        self.ambient
+            .restriction(&self.intersect(set_to.clone()), section)
+    }
+}
+
+impl<X, S> Sheaf<S> for Subspace<'_, X>
+where
+    X: FiniteTopologicalSpace + Sheaf<S>,
+    X::OpenSet: FromIterator<X::Point>,
+    S: Section<TopologicalSpace = X>,
+{
+    fn gluing(&self, sections: Vec<(&X::OpenSet, S)>) -> Option<S> {
+        let domains: Vec<X::OpenSet> = sections
+            .iter()
+            .map(|(domain, _)| self.intersect((*domain).clone()))
+            .collect();
+        let restricted = domains
+            .iter()
+            .zip(sections)
+            .map(|(domain, (_, section))| (domain, section.restrict(domain.clone())))
+            .collect();
+        self.ambient.gluing(restricted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        spaces::{
+            finite_topology::FiniteTopology,
+            undirected_graph::{Data, UndirectedGraph},
+        },
+        test_support::set,
+    };
+
+    #[test]
+    fn subspace_topology() {
+        let space = FiniteTopology::from_subbasis(
+            set(&['a', 'b', 'c']),
+            vec![set(&['a']), set(&['a', 'b'])],
+        );
+        let subspace = Subspace::new(&space, set(&['b', 'c']));
+        assert!(subspace.is_open(set(&['b'])));
+        assert!(!subspace.is_open(set(&['c'])));
+        assert!(!subspace.is_open(set(&['a'])));
+        assert_eq!(subspace.neighborhood('c'), set(&['b', 'c']));
+        assert_eq!(subspace.open_sets().len(), 3);
+        assert!(subspace.inclusion().is_continuous(&subspace, &space));
+    }
+
+    #[test]
+    #[should_panic]
+    fn points_must_be_in_the_space() {
+        let space = FiniteTopology::from_subbasis(set(&['a']), vec![]);
+        Subspace::new(&space, set(&['z']));
+    }
+
+    #[test]
+    fn restrict_sections_to_region() {
+        let graph = UndirectedGraph::new(set(&[1, 2, 3, 4]), set(&[(1, 2), (2, 3), (3, 4)]));
+        let region = Subspace::new(&graph, set(&[2, 3]));
+        let section: HashMap<usize, Data<i32>> = vec![(1, Data(1)), (2, Data(2)), (3, Data(3))]
+            .into_iter()
+            .collect();
+
+        let restricted = region.restrict_section(&section);
+        assert_eq!(
+            restricted.keys().cloned().collect::<HashSet<_>>(),
+            set(&[2, 3])
+        );
+
+        let restricted = region.restriction(&set(&[1, 2]), &section);
+        assert_eq!(restricted, vec![(2, Data(2))].into_iter().collect());
+        assert_eq!(region.neighborhood(2), set(&[3]));
+    }
+}
@@ -1,7 +1,7 @@
 use std::hash::Hash;
 
 use super::*;
-use crate::topology::{OpenSet, Section};
+use crate::{topology::Section, union_find::UnionFind};
 
 pub struct UndirectedGraph {
     pub vertices: HashSet<usize>,
@@ -25,17 +25,6 @@ impl UndirectedGraph {
     }
 }
 
-impl OpenSet for HashSet<usize> {
-    type Point = usize;
-
-    fn intersect(&self, other: Self) -> Self {
-        self.intersection(&other).cloned().collect()
-    }
-    fn union(&self, other: Self) -> Self {
-        self.union(&other).cloned().collect()
-    }
-}
-
 impl TopologicalSpace for UndirectedGraph {
     type Point = usize;
 
@@ -65,6 +54,42 @@ impl TopologicalSpace for UndirectedGraph {
     }
 }
 
+impl FiniteTopologicalSpace for UndirectedGraph {
+    // Every set is open, so each vertex is open on its own.
+    fn minimal_neighborhood(&self, point: Self::Point) -> HashSet<Self::Point> {
+        HashSet::from([point])
+    }
+
+    /// The components of the network, following the edges, ordered by
+    /// smallest vertex. Every vertex is open on its own, so these are not the
+    /// components of the discrete topology, which would be single vertices.
+    fn connected_components(&self) -> Vec<HashSet<Self::Point>> {
+        let mut vertices: Vec<usize> = self.vertices.iter().cloned().collect();
+        vertices.sort();
+        let index: HashMap<usize, usize> =
+            vertices.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+        let mut components = UnionFind::new(vertices.len());
+        for (a, b) in &self.edges {
+            components.union(index[a], index[b]);
+        }
+        components
+            .sets()
+            .into_iter()
+            .map(|set| set.into_iter().map(|i| vertices[i]).collect())
+            .collect()
+    }
+
+    // Two vertices are joined by a path exactly when they are in the same
+    // component of the network.
+    fn path_components(&self) -> Vec<HashSet<Self::Point>> {
+        self.connected_components()
+    }
+
+    fn is_connected(&self) -> bool {
+        self.connected_components().len() <= 1
+    }
+}
+
 impl MetricSpace for UndirectedGraph {
     type Distance = Option<usize>;
 
@@ -90,7 +115,7 @@ impl MetricSpace for UndirectedGraph {
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
-pub struct Data<T: Eq + Hash + Clone>(T);
+pub struct Data<T: Eq + Hash + Clone>(pub T);
 
 impl<T: Eq + Hash + Clone> Section for HashMap<usize, Data<T>> {
     type TopologicalSpace = UndirectedGraph;
@@ -192,6 +217,32 @@ mod tests {
         assert_eq!(graph.distance(1, 5), None);
     }
 
+    #[test]
+    fn components() {
+        let graph = create_graph();
+        let components: Vec<HashSet<usize>> = vec![
+            vec![1, 2, 3, 4].into_iter().collect(),
+            vec![5].into_iter().collect(),
+        ];
+        assert_eq!(graph.connected_components(), components);
+        assert_eq!(graph.path_components(), components);
+        assert!(!graph.is_connected());
+        let edges = vec![(1, 2), (2, 3), (4, 3), (5, 4)].into_iter().collect();
+        let connected = UndirectedGraph::new(graph.vertices.clone(), edges);
+        assert_eq!(connected.connected_components().len(), 1);
+        assert_eq!(connected.path_components().len(), 1);
+        assert!(connected.is_connected());
+    }
+
+    #[test]
+    fn every_set_is_closed() {
+        let graph = create_graph();
+        let set = vec![1, 3].into_iter().collect::<HashSet<_>>();
+        assert_eq!(graph.closure(&set), set);
+        assert_eq!(graph.interior(&set), set);
+        assert!(graph.boundary(&set).is_empty());
+    }
+
     #[test]
     fn restriction() {
         let graph = create_graph();
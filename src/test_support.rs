@@ -0,0 +1,49 @@
+// Fixtures shared by the tests of several modules.
+
+use std::{collections::HashSet, hash::Hash, rc::Rc};
+
+use crate::spaces::{
+    cell_complex::{CellComplex, Point, Skeleton},
+    constructions::FiniteCell,
+    finite_topology::FiniteTopology,
+};
+
+pub fn set<T: Eq + Hash + Clone>(points: &[T]) -> HashSet<T> {
+    points.iter().cloned().collect()
+}
+
+// The Sierpiński space, in which `a` is open and `b` is closed.
+pub fn sierpinski() -> FiniteTopology<char> {
+    FiniteTopology::from_basis(set(&['a', 'b']), vec![set(&['a']), set(&['a', 'b'])])
+}
+
+// A complex glued from cells that are discrete in themselves, so all the
+// topology comes from the way they are glued. The cells are given with their
+// dimension, in the order they are glued.
+pub fn complex_from(cells: Vec<(Vec<i32>, usize)>) -> CellComplex<i32> {
+    let dim = cells.iter().map(|(_, d)| *d).max().unwrap_or(0);
+    let mut skeleton = Skeleton::new();
+    for (points, dim) in cells {
+        let neighborhoods = points
+            .into_iter()
+            .map(|p| (Point(p), HashSet::from([Point(p)])))
+            .collect();
+        skeleton.include_cell(Rc::new(FiniteCell::new(dim, neighborhoods)));
+    }
+    CellComplex::new(skeleton, dim)
+}
+
+// Two vertices joined by an edge.
+pub fn interval() -> CellComplex<i32> {
+    complex_from(vec![(vec![0], 0), (vec![1], 0), (vec![0, 1], 1)])
+}
+
+// Two vertices joined by two edges, through the points 2 and 3.
+pub fn circle() -> CellComplex<i32> {
+    complex_from(vec![
+        (vec![0], 0),
+        (vec![1], 0),
+        (vec![0, 1, 2], 1),
+        (vec![0, 1, 3], 1),
+    ])
+}
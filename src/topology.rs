@@ -1,4 +1,10 @@
-use std::collections::HashSet;
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    rc::Rc,
+};
+
+use crate::union_find::UnionFind;
 
 pub trait OpenSet: IntoIterator<Item = Self::Point> + Clone {
     type Point;
@@ -6,6 +12,18 @@ pub trait OpenSet: IntoIterator<Item = Self::Point> + Clone {
     fn union(&self, other: Self) -> Self;
 }
 
+/// Implements OpenSets as HashSets of points, which is how every finite space
+/// in the crate represents them.
+impl<P: Eq + Hash + Clone> OpenSet for HashSet<P> {
+    type Point = P;
+    fn intersect(&self, other: Self) -> Self {
+        self.intersection(&other).cloned().collect()
+    }
+    fn union(&self, other: Self) -> Self {
+        self.union(&other).cloned().collect()
+    }
+}
+
 pub trait TopologicalSpace {
     type Point;
     type OpenSet: OpenSet<Point = Self::Point>;
@@ -14,6 +32,219 @@ pub trait TopologicalSpace {
     fn is_open(&self, open_set: Self::OpenSet) -> bool;
 }
 
+/// Operators for finite topological spaces, where every point has a smallest
+/// open neighborhood and everything else can be computed from those.
+///
+/// Spaces implement [`FiniteTopologicalSpace::minimal_neighborhood`] and get
+/// the rest. Their points have to be hashable and cloneable, since the
+/// results are sets of points.
+pub trait FiniteTopologicalSpace: TopologicalSpace<Point: Eq + Hash + Clone> {
+    /// The smallest open set containing `point`, which is the intersection of
+    /// all of them.
+    fn minimal_neighborhood(&self, point: Self::Point) -> HashSet<Self::Point>;
+
+    /// Every open set of the space, generated as the unions of the smallest
+    /// neighborhoods. A finite space can have exponentially many open sets, so
+    /// this is only practical for small spaces.
+    fn open_sets(&self) -> Vec<HashSet<Self::Point>> {
+        let points: Vec<Self::Point> = self.points().into_iter().collect();
+        let index: HashMap<&Self::Point, usize> =
+            points.iter().enumerate().map(|(i, p)| (p, i)).collect();
+        let mut generators: Vec<Vec<bool>> = Vec::new();
+        for point in &points {
+            let mut member = vec![false; points.len()];
+            for p in self.minimal_neighborhood(point.clone()) {
+                member[index[&p]] = true;
+            }
+            if !generators.contains(&member) {
+                generators.push(member);
+            }
+        }
+
+        let mut seen: HashSet<Vec<bool>> = HashSet::new();
+        let mut queue = vec![vec![false; points.len()]];
+        seen.insert(queue[0].clone());
+        while let Some(set) = queue.pop() {
+            for generator in &generators {
+                let union: Vec<bool> = set.iter().zip(generator).map(|(a, b)| *a || *b).collect();
+                if seen.insert(union.clone()) {
+                    queue.push(union);
+                }
+            }
+        }
+        seen.into_iter()
+            .map(|member| {
+                (0..points.len())
+                    .filter(|&i| member[i])
+                    .map(|i| points[i].clone())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// The points every open neighborhood of which meets `set`.
+    fn closure(&self, set: &HashSet<Self::Point>) -> HashSet<Self::Point> {
+        self.points()
+            .into_iter()
+            .filter(|p| {
+                self.minimal_neighborhood(p.clone())
+                    .iter()
+                    .any(|q| set.contains(q))
+            })
+            .collect()
+    }
+
+    /// The points of `set` that have an open neighborhood inside `set`.
+    fn interior(&self, set: &HashSet<Self::Point>) -> HashSet<Self::Point> {
+        self.points()
+            .into_iter()
+            .filter(|p| set.contains(p) && self.minimal_neighborhood(p.clone()).is_subset(set))
+            .collect()
+    }
+
+    /// The closure of `set` without its interior.
+    fn boundary(&self, set: &HashSet<Self::Point>) -> HashSet<Self::Point> {
+        let interior = self.interior(set);
+        self.closure(set)
+            .into_iter()
+            .filter(|p| !interior.contains(p))
+            .collect()
+    }
+
+    /// The limit points of `set`: the points every open neighborhood of which
+    /// meets `set` in a point other than themselves.
+    fn derived_set(&self, set: &HashSet<Self::Point>) -> HashSet<Self::Point> {
+        self.points()
+            .into_iter()
+            .filter(|p| {
+                self.minimal_neighborhood(p.clone())
+                    .iter()
+                    .any(|q| q != p && set.contains(q))
+            })
+            .collect()
+    }
+
+    fn is_closed(&self, set: &HashSet<Self::Point>) -> bool {
+        self.closure(set) == *set
+    }
+
+    fn is_dense(&self, set: &HashSet<Self::Point>) -> bool {
+        self.closure(set) == self.points()
+    }
+
+    /// The connected components. Two points lie in the same component exactly
+    /// when they are joined by a chain of points, each of which lies in the
+    /// smallest neighborhood of the next or of the previous one.
+    fn connected_components(&self) -> Vec<HashSet<Self::Point>> {
+        let points: Vec<Self::Point> = self.points().into_iter().collect();
+        let index: HashMap<&Self::Point, usize> =
+            points.iter().enumerate().map(|(i, p)| (p, i)).collect();
+        let mut components = UnionFind::new(points.len());
+        for (i, point) in points.iter().enumerate() {
+            for neighbor in self.minimal_neighborhood(point.clone()) {
+                if let Some(&j) = index.get(&neighbor) {
+                    components.union(i, j);
+                }
+            }
+        }
+        components
+            .sets()
+            .into_iter()
+            .map(|set| set.into_iter().map(|i| points[i].clone()).collect())
+            .collect()
+    }
+
+    /// The path components. Finite spaces are locally path connected, since
+    /// `t ↦ x` for `t < 1` and `1 ↦ y` is a path whenever `x` lies in the
+    /// smallest neighborhood of `y`, so these are the connected components.
+    fn path_components(&self) -> Vec<HashSet<Self::Point>> {
+        self.connected_components()
+    }
+
+    /// Whether the space has at most one component. Every finite space is
+    /// also compact, so this is the only one of the two worth computing.
+    fn is_connected(&self) -> bool {
+        self.connected_components().len() <= 1
+    }
+}
+
+/// A map sending the points of `X` to points of `Y`. Whether it is continuous
+/// depends on the topologies of the two spaces, and can be checked with
+/// [`ContinuousMap::is_continuous`].
+pub struct ContinuousMap<X: TopologicalSpace, Y: TopologicalSpace> {
+    map: Rc<dyn Fn(X::Point) -> Y::Point>,
+}
+
+impl<X: TopologicalSpace, Y: TopologicalSpace> Clone for ContinuousMap<X, Y> {
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<X: TopologicalSpace, Y: TopologicalSpace> ContinuousMap<X, Y> {
+    pub fn new(map: impl Fn(X::Point) -> Y::Point + 'static) -> Self {
+        Self { map: Rc::new(map) }
+    }
+
+    pub fn apply(&self, point: X::Point) -> Y::Point {
+        (self.map)(point)
+    }
+
+    /// The composite that applies `self` first and then `other`.
+    pub fn then<Z: TopologicalSpace>(&self, other: &ContinuousMap<Y, Z>) -> ContinuousMap<X, Z>
+    where
+        X::Point: 'static,
+        Y::Point: 'static,
+        Z::Point: 'static,
+    {
+        let (first, second) = (self.map.clone(), other.map.clone());
+        ContinuousMap {
+            map: Rc::new(move |p| second(first(p))),
+        }
+    }
+}
+
+impl<X: FiniteTopologicalSpace, Y: FiniteTopologicalSpace> ContinuousMap<X, Y> {
+    pub fn image(&self, set: &HashSet<X::Point>) -> HashSet<Y::Point> {
+        set.iter().map(|p| self.apply(p.clone())).collect()
+    }
+
+    pub fn preimage(&self, domain: &X, set: &HashSet<Y::Point>) -> HashSet<X::Point> {
+        domain
+            .points()
+            .into_iter()
+            .filter(|p| set.contains(&self.apply(p.clone())))
+            .collect()
+    }
+
+    /// Checks that the map lands in the codomain and that the preimage of every
+    /// open set is open. Every open set of a finite space is a union of
+    /// smallest neighborhoods, so it is enough to check the preimages of
+    /// those.
+    pub fn is_continuous(&self, domain: &X, codomain: &Y) -> bool
+    where
+        X::OpenSet: FromIterator<X::Point>,
+    {
+        let points = codomain.points();
+        domain
+            .points()
+            .into_iter()
+            .all(|p| points.contains(&self.apply(p)))
+            && points.into_iter().all(|q| {
+                let preimage = self.preimage(domain, &codomain.minimal_neighborhood(q));
+                domain.is_open(preimage.into_iter().collect())
+            })
+    }
+}
+
+impl<X: TopologicalSpace> ContinuousMap<X, X> {
+    pub fn identity() -> Self {
+        Self::new(|p| p)
+    }
+}
+
 pub trait MetricSpace: TopologicalSpace {
     type Distance;
     fn distance(
@@ -95,3 +326,84 @@ pub trait Section: Eq + PartialEq + Clone {
         section: Self,
     ) -> Option<Self>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        spaces::{finite_topology::FiniteTopology, undirected_graph::UndirectedGraph},
+        test_support::sierpinski,
+    };
+
+    fn two_vertices() -> UndirectedGraph {
+        UndirectedGraph::new(vec![0, 1].into_iter().collect(), HashSet::new())
+    }
+
+    #[test]
+    fn identity_and_constants_are_continuous() {
+        let space = sierpinski();
+        assert!(ContinuousMap::<FiniteTopology<char>, _>::identity().is_continuous(&space, &space));
+        let constant = ContinuousMap::<FiniteTopology<char>, FiniteTopology<char>>::new(|_| 'b');
+        assert!(constant.is_continuous(&space, &space));
+    }
+
+    #[test]
+    fn swap_is_not_continuous() {
+        let space = sierpinski();
+        let swap = ContinuousMap::<FiniteTopology<char>, FiniteTopology<char>>::new(|p| {
+            if p == 'a' {
+                'b'
+            } else {
+                'a'
+            }
+        });
+        assert!(!swap.is_continuous(&space, &space));
+        assert_eq!(
+            swap.preimage(&space, &vec!['a'].into_iter().collect()),
+            vec!['b'].into_iter().collect()
+        );
+    }
+
+    #[test]
+    fn maps_out_of_discrete_spaces() {
+        let (graph, space) = (two_vertices(), sierpinski());
+        let to_sierpinski = ContinuousMap::<UndirectedGraph, FiniteTopology<char>>::new(|v| {
+            if v == 0 {
+                'a'
+            } else {
+                'b'
+            }
+        });
+        assert!(to_sierpinski.is_continuous(&graph, &space));
+        let to_graph = ContinuousMap::<FiniteTopology<char>, UndirectedGraph>::new(|p| {
+            if p == 'a' {
+                0
+            } else {
+                1
+            }
+        });
+        assert!(!to_graph.is_continuous(&space, &graph));
+        let outside = ContinuousMap::<UndirectedGraph, UndirectedGraph>::new(|v| v + 5);
+        assert!(!outside.is_continuous(&graph, &graph));
+    }
+
+    #[test]
+    fn composition() {
+        let (graph, space) = (two_vertices(), sierpinski());
+        let to_sierpinski = ContinuousMap::<UndirectedGraph, FiniteTopology<char>>::new(|v| {
+            if v == 0 {
+                'a'
+            } else {
+                'b'
+            }
+        });
+        let collapse = ContinuousMap::<FiniteTopology<char>, FiniteTopology<char>>::new(|_| 'a');
+        let composite = to_sierpinski.then(&collapse);
+        assert_eq!(composite.apply(1), 'a');
+        assert!(composite.is_continuous(&graph, &space));
+        assert_eq!(
+            composite.image(&graph.points()),
+            vec!['a'].into_iter().collect()
+        );
+    }
+}